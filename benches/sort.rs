@@ -47,11 +47,170 @@ mod tests {
     }
 
     def_tests!(2 => mod length_2 { SortingNetwork2 });
+    def_tests!(3 => mod length_3 { SortingNetwork3 });
     def_tests!(4 => mod length_4 { SortingNetwork4 });
+    def_tests!(5 => mod length_5 { SortingNetwork5 });
     def_tests!(8 => mod length_8 { SortingNetwork8 });
+    def_tests!(12 => mod length_12 { SortingNetwork12 });
     def_tests!(16 => mod length_16 { SortingNetwork16 });
     def_tests!(32 => mod length_32 { SortingNetwork32 });
     def_tests!(64 => mod length_64 { SortingNetwork64 });
+    #[cfg(feature = "wide-networks")]
     def_tests!(128 => mod length_128 { SortingNetwork128 });
+    #[cfg(feature = "wide-networks")]
     def_tests!(256 => mod length_256 { SortingNetwork256 });
+
+    def_tests!(4 => mod bitonic_length_4 { BitonicSortingNetwork4 });
+    def_tests!(8 => mod bitonic_length_8 { BitonicSortingNetwork8 });
+    def_tests!(16 => mod bitonic_length_16 { BitonicSortingNetwork16 });
+    def_tests!(32 => mod bitonic_length_32 { BitonicSortingNetwork32 });
+    def_tests!(64 => mod bitonic_length_64 { BitonicSortingNetwork64 });
+    #[cfg(feature = "wide-networks")]
+    def_tests!(128 => mod bitonic_length_128 { BitonicSortingNetwork128 });
+    #[cfg(feature = "wide-networks")]
+    def_tests!(256 => mod bitonic_length_256 { BitonicSortingNetwork256 });
+
+    def_tests!(4 => mod pairwise_length_4 { PairwiseSortingNetwork4 });
+    def_tests!(8 => mod pairwise_length_8 { PairwiseSortingNetwork8 });
+    def_tests!(16 => mod pairwise_length_16 { PairwiseSortingNetwork16 });
+    def_tests!(32 => mod pairwise_length_32 { PairwiseSortingNetwork32 });
+    def_tests!(64 => mod pairwise_length_64 { PairwiseSortingNetwork64 });
+    #[cfg(feature = "wide-networks")]
+    def_tests!(128 => mod pairwise_length_128 { PairwiseSortingNetwork128 });
+    #[cfg(feature = "wide-networks")]
+    def_tests!(256 => mod pairwise_length_256 { PairwiseSortingNetwork256 });
+
+    def_tests!(5 => mod bose_nelson_length_5 { BoseNelsonSortingNetwork5 });
+    def_tests!(9 => mod bose_nelson_length_9 { BoseNelsonSortingNetwork9 });
+    def_tests!(12 => mod bose_nelson_length_12 { BoseNelsonSortingNetwork12 });
+    def_tests!(17 => mod bose_nelson_length_17 { BoseNelsonSortingNetwork17 });
+
+    def_tests!(4 => mod odd_even_transposition_length_4 { OddEvenTranspositionSortingNetwork4 });
+    def_tests!(8 => mod odd_even_transposition_length_8 { OddEvenTranspositionSortingNetwork8 });
+    def_tests!(16 => mod odd_even_transposition_length_16 { OddEvenTranspositionSortingNetwork16 });
+    def_tests!(32 => mod odd_even_transposition_length_32 { OddEvenTranspositionSortingNetwork32 });
+
+    def_tests!(9 => mod optimal_length_9 { OptimalSortingNetwork9 });
+    def_tests!(12 => mod optimal_length_12 { OptimalSortingNetwork12 });
+    def_tests!(16 => mod optimal_length_16 { OptimalSortingNetwork16 });
+
+    def_tests!(9 => mod depth_optimal_length_9 { DepthOptimalSortingNetwork9 });
+    def_tests!(12 => mod depth_optimal_length_12 { DepthOptimalSortingNetwork12 });
+    def_tests!(16 => mod depth_optimal_length_16 { DepthOptimalSortingNetwork16 });
+
+    macro_rules! def_hybrid_tests {
+        ($length:expr => mod $module:ident) => {
+            mod $module {
+                use super::*;
+
+                #[bench]
+                fn network_sort(bencher: &mut Bencher) {
+                    let items = test::black_box(shuffled($length));
+                    bencher.iter(|| {
+                        let mut items = items.clone();
+                        items.network_sort();
+                        let _ = test::black_box(items);
+                    });
+                }
+
+                #[bench]
+                fn stdlib_unstable(bencher: &mut Bencher) {
+                    let items = test::black_box(shuffled($length));
+                    bencher.iter(|| {
+                        let mut items = items.clone();
+                        items.sort_unstable();
+                        let _ = test::black_box(items);
+                    });
+                }
+            }
+        }
+    }
+
+    def_hybrid_tests!(1_000 => mod hybrid_1_000);
+    def_hybrid_tests!(100_000 => mod hybrid_100_000);
+
+    mod select {
+        use super::*;
+
+        #[bench]
+        fn sort(bencher: &mut Bencher) {
+            let items = test::black_box(shuffled(32));
+            bencher.iter(|| {
+                let mut items = items.clone();
+                SortingNetwork32::new().sort(&mut items[..]);
+                let _ = test::black_box(items[16]);
+            });
+        }
+
+        #[bench]
+        fn select_nth(bencher: &mut Bencher) {
+            let items = test::black_box(shuffled(32));
+            bencher.iter(|| {
+                let mut items = items.clone();
+                let _ = test::black_box(*SortingNetwork32::new().select_nth(&mut items[..], 16));
+            });
+        }
+
+        #[bench]
+        fn selection_network(bencher: &mut Bencher) {
+            let items = test::black_box(shuffled(32));
+            let network = SelectionNetwork::nth(&SortingNetwork32::new(), 32, 16);
+            bencher.iter(|| {
+                let mut items = items.clone();
+                network.sort(&mut items[..]);
+                let _ = test::black_box(items[16]);
+            });
+        }
+
+        #[bench]
+        fn runtime_select_nth(bencher: &mut Bencher) {
+            let items = test::black_box(shuffled(32));
+            bencher.iter(|| {
+                let mut items = items.clone();
+                let _ = test::black_box(*RuntimeSortingNetwork::new().select_nth(&mut items[..], 16));
+            });
+        }
+    }
+
+    mod chunks {
+        use super::*;
+
+        const CHUNKS: usize = 10_000;
+
+        fn records() -> Vec<u32> {
+            shuffled(16 * CHUNKS).into_iter().map(|item| item as u32).collect()
+        }
+
+        #[bench]
+        fn sort_chunks_exact(bencher: &mut Bencher) {
+            let items = test::black_box(records());
+            bencher.iter(|| {
+                let mut items = items.clone();
+                SortingNetwork16::new().sort_chunks_exact(&mut items[..]);
+                let _ = test::black_box(items);
+            });
+        }
+
+        #[bench]
+        fn sort_chunks_interleaved(bencher: &mut Bencher) {
+            let items = test::black_box(records());
+            bencher.iter(|| {
+                let mut items = items.clone();
+                SortingNetwork16::new().sort_chunks_interleaved(&mut items[..]);
+                let _ = test::black_box(items);
+            });
+        }
+
+        #[bench]
+        fn stdlib(bencher: &mut Bencher) {
+            let items = test::black_box(records());
+            bencher.iter(|| {
+                let mut items = items.clone();
+                for chunk in items.chunks_exact_mut(16) {
+                    chunk.sort_unstable();
+                }
+                let _ = test::black_box(items);
+            });
+        }
+    }
 }
@@ -20,6 +20,7 @@ use std::path::Path;
 use std::process::Command;
 
 include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generate.rs"));
+include!(concat!(env!("CARGO_MANIFEST_DIR"), "/optimal.rs"));
 
 #[allow(unused_macros)]
 macro_rules! scaffold_swap_checked {
@@ -38,101 +39,118 @@ macro_rules! scaffold_swap_checked {
 }
 
 macro_rules! scaffold_sorting_network_n {
-    (order: $order:expr) => ({
-        let last_group = Network::new($order).last().unwrap();
-        let patterns: Vec<_> = last_group
-            .flat_map(|block| block.flat_map(|stage| {
-                let MetaPattern {
-                    start,
-                    count,
-                    length,
-                } = stage.meta_pattern();
-                let (start, count, length) = (
-                    start as u8, count as u8, length as u8
-                );
-                quote! { (#start, #count, #length) }
-            })).collect();
-
-        let count = patterns.len();
-
-        let operations = quote! {
-            let patterns: [(u8, u8, u8); #count] = [#(#patterns),*];
-            for (start, count, length) in patterns.iter().cloned() {
-                let (start, count, length) = (
-                    start as usize, count as usize, length as usize
-                );
-                let gap = 2 * length;
-                for i in 0..(count as usize) {
-                    let offset = i * gap;
-                    for j in 0..(length as usize) {
-                        let min = start + offset + j;
-                        let max = min + length;
-                        unsafe {
-                            swap_unchecked(slice, min, max, &compare);
-                        }
-                    }
-                }
-                println!();
-            }
-        };
+    (width: $width:expr) => ({
+        let width: usize = $width;
+        let order = width.next_power_of_two().trailing_zeros() as usize;
+
+        let pairs: Vec<Pair> = Network::new(order)
+            .flat_map(|group| group)
+            .flat_map(|block| block)
+            .flat_map(|stage| stage)
+            .flat_map(|pattern| pattern)
+            .filter(|pair| pair.max < width)
+            .collect();
+
+        scaffold_fixed_size_network_n!(
+            family: "SortingNetwork",
+            doc: "Optimized sorting network",
+            width: width,
+            pairs: pairs
+        )
+    });
+}
 
-        let order = $order;
-        let width = (1 << $order) as usize;
-        let name = syn::Ident::from(format!("SortingNetwork{}", width));
+macro_rules! scaffold_fixed_size_network_n {
+    (family: $family:expr, doc: $doc:expr, width: $width:expr, pairs: $pairs:expr) => ({
+        let width: usize = $width;
+        let pairs: Vec<Pair> = $pairs;
+        let doc = format!("{} for slices of length {}.", $doc, width);
 
-        let sub_width = (1 << ($order - 1)) as usize;
-        let sub_name = syn::Ident::from(format!("SortingNetwork{}", sub_width));
+        let count = pairs.len();
+        let depth = depth(width, &pairs);
+
+        let comparators: Vec<_> = pairs.iter().map(|&Pair { min, max }| {
+            let (min, max) = (min as u8, max as u8);
+            quote! { (#min, #max) }
+        }).collect();
+
+        let family = syn::Ident::from($family);
+        let name = syn::Ident::from(format!("{}{}", $family, width));
 
         quote! {
-            /// Optimized sorting network for slices of specific length.
-            #[derive(Clone, Copy)]
-            pub struct #name;
-
-            impl #name {
-                /// Creates a sorting network for slices of specific length.
-                #[inline]
-                pub fn new() -> Self {
-                    #name
-                }
+            #[doc = #doc]
+            pub type #name = #family<#width>;
+
+            impl FixedSizeSortingNetwork for #family<#width> {
+                const WIDTH: usize = #width;
+                const COMPARATORS: usize = #count;
+                const DEPTH: usize = #depth;
+                const PAIRS: &'static [(u8, u8)] = &[#(#comparators),*];
             }
+        }
+    });
+}
 
-            impl SortingNetworkTrait for #name {
-                fn sort_by<T, F>(&self, slice: &mut [T], compare: F)
-                where
-                    F: Fn(&T, &T) -> Ordering
-                {
-                    let len = slice.len();
-                    assert!(len == #width, "Expected slice of length {}", #width);
-                    {
-                        let (lhs, rhs) = slice.split_at_mut(len / 2);
-                        let sub_sort = #sub_name::new();
-                        sub_sort.sort_by(lhs, &compare);
-                        sub_sort.sort_by(rhs, &compare);
-                    }
-                    #(#operations)*
-                }
-            }
+macro_rules! scaffold_sorting_network_lookup {
+    (max_width: $max_width:expr) => ({
+        let max_width: usize = $max_width;
+        let arms: Vec<_> = (2..(max_width + 1)).map(|width| {
+            quote! { #width => Some(SortingNetwork::<#width>::pairs()), }
+        }).collect();
 
-            impl FixedSizeSortingNetwork for #name {
-                #[inline]
-                fn order() -> usize {
-                    #order
+        quote! {
+            /// The length of the largest generated sorting network.
+            pub const MAX_WIDTH: usize = #max_width;
+
+            /// Returns the comparators of the generated network for slices of length `width`.
+            fn sorting_network_pairs(width: usize) -> Option<&'static [(u8, u8)]> {
+                match width {
+                    #(#arms)*
+                    _ => None,
                 }
             }
+        }
+    });
+}
 
-            impl ::std::fmt::Debug for #name {
-                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                    debug::debug_fmt(#order, f)
-                }
+macro_rules! scaffold_merging_network_mxn {
+    (lhs: $lhs:expr, rhs: $rhs:expr) => ({
+        let (lhs, rhs): (usize, usize) = ($lhs, $rhs);
+
+        let pairs: Vec<Pair> = OddEvenMerge::new(lhs, rhs).collect();
+
+        let count = pairs.len();
+        let depth = depth(lhs + rhs, &pairs);
+
+        let comparators: Vec<_> = pairs.iter().map(|&Pair { min, max }| {
+            let (min, max) = (min as u8, max as u8);
+            quote! { (#min, #max) }
+        }).collect();
+
+        let name = syn::Ident::from(format!("MergingNetwork{}x{}", lhs, rhs));
+        let doc = format!(
+            "Optimized merging network for sorted runs of lengths {} and {}.", lhs, rhs
+        );
+
+        quote! {
+            #[doc = #doc]
+            pub type #name = MergingNetwork<#lhs, #rhs>;
+
+            impl FixedSizeMergingNetwork for MergingNetwork<#lhs, #rhs> {
+                const LHS: usize = #lhs;
+                const RHS: usize = #rhs;
+                const COMPARATORS: usize = #count;
+                const DEPTH: usize = #depth;
+                const PAIRS: &'static [(u8, u8)] = &[#(#comparators),*];
             }
         }
     });
 }
 
 macro_rules! scaffold_tests {
-    (max_order: $max_order:expr) => ({
-        let tests: Vec<_> = (1..($max_order + 1)).map(|order| {
-            scaffold_test!(order: order)
+    (max_width: $max_width:expr) => ({
+        let tests: Vec<_> = (2..($max_width + 1)).map(|width| {
+            scaffold_test!(width: width)
         }).collect();
 
         quote! {
@@ -154,8 +172,8 @@ macro_rules! scaffold_tests {
 }
 
 macro_rules! scaffold_test {
-    (order: $order:expr) => ({
-        let width: usize = 1 << $order;
+    (width: $width:expr) => ({
+        let width: usize = $width;
         let mod_name = syn::Ident::from(format!("length_{}", width));
         let name = syn::Ident::from(format!("SortingNetwork{}", width));
 
@@ -177,6 +195,26 @@ macro_rules! scaffold_test {
     })
 }
 
+/// Returns the comparators of a network from `optimal.rs`, layer by layer.
+fn table_pairs(layers: &[&[(usize, usize)]]) -> Vec<Pair> {
+    layers.iter()
+        .flat_map(|layer| layer.iter())
+        .map(|&(min, max)| Pair::new(min, max))
+        .collect()
+}
+
+/// Returns the number of parallel stages of a network,
+/// with each comparator placed into the earliest possible stage.
+fn depth(width: usize, pairs: &[Pair]) -> usize {
+    let mut depths = vec![0; width];
+    for pair in pairs {
+        let depth = depths[pair.min].max(depths[pair.max]) + 1;
+        depths[pair.min] = depth;
+        depths[pair.max] = depth;
+    }
+    depths.into_iter().max().unwrap_or(0)
+}
+
 fn main() {
     let out_dir = env::var("OUT_DIR").unwrap();
     let file_path = Path::new(&out_dir).join("generated.rs");
@@ -188,18 +226,77 @@ fn main() {
         use std::cmp::Ordering;
     });
 
-    let max_order = 8;
+    // Networks for more than 64 wires make up the bulk of the generated
+    // source, so they are only generated with the `wide-networks` feature:
+    let wide = env::var_os("CARGO_FEATURE_WIDE_NETWORKS").is_some();
+
+    let max_width: usize = if wide { 256 } else { 64 };
+    for width in 2..(max_width + 1) {
+        tokens.push(scaffold_sorting_network_n!(width: width));
+    }
+
+    tokens.push(scaffold_sorting_network_lookup!(max_width: max_width));
+
+    let max_order = max_width.trailing_zeros() as usize;
     for order in 1..(max_order + 1) {
-        tokens.push(scaffold_sorting_network_n!(order: order));
+        tokens.push(scaffold_fixed_size_network_n!(
+            family: "BitonicSortingNetwork",
+            doc: "Bitonic sorting network",
+            width: 1 << order,
+            pairs: BitonicSort::new(order).collect()
+        ));
+        tokens.push(scaffold_fixed_size_network_n!(
+            family: "PairwiseSortingNetwork",
+            doc: "Pairwise sorting network",
+            width: 1 << order,
+            pairs: PairwiseSort::new(order).collect()
+        ));
+    }
+
+    for width in 2..33 {
+        tokens.push(scaffold_fixed_size_network_n!(
+            family: "BoseNelsonSortingNetwork",
+            doc: "Bose-Nelson sorting network",
+            width: width,
+            pairs: BoseNelsonSort::new(width).collect()
+        ));
+        tokens.push(scaffold_fixed_size_network_n!(
+            family: "OddEvenTranspositionSortingNetwork",
+            doc: "Odd-even transposition sorting network",
+            width: width,
+            pairs: OddEvenTransposition::new(width).collect()
+        ));
     }
 
-    tokens.push(scaffold_tests!(max_order: max_order));
+    for width in 2..(SIZE_OPTIMAL_NETWORKS.len() + 2) {
+        tokens.push(scaffold_fixed_size_network_n!(
+            family: "OptimalSortingNetwork",
+            doc: "Best-known size-optimal sorting network",
+            width: width,
+            pairs: table_pairs(SIZE_OPTIMAL_NETWORKS[width - 2])
+        ));
+        tokens.push(scaffold_fixed_size_network_n!(
+            family: "DepthOptimalSortingNetwork",
+            doc: "Best-known depth-optimal sorting network",
+            width: width,
+            pairs: table_pairs(DEPTH_OPTIMAL_NETWORKS[width - 2])
+        ));
+    }
 
-    let tokens = quote! {
-        #(#tokens)*
-    };
+    let max_run = if wide { 16 } else { 8 };
+    for lhs in 1..(max_run + 1) {
+        for rhs in 1..(max_run + 1) {
+            tokens.push(scaffold_merging_network_mxn!(lhs: lhs, rhs: rhs));
+        }
+    }
 
-    f.write_all(tokens.to_string().as_bytes()).unwrap();
+    tokens.push(scaffold_tests!(max_width: max_width));
+
+    // Write one item per line, as rustc slows down considerably on
+    // huge single-line sources whenever `rustfmt` is not available:
+    for tokens in tokens {
+        writeln!(f, "{}", tokens).unwrap();
+    }
 
     let fmt_result = Command::new("rustfmt")
         .arg("--write-mode")
@@ -243,6 +243,456 @@ impl Pair {
     }
 }
 
+/// Iterator over the comparators of Batcher's bitonic sorting network
+/// for `2^order` wires, in flat form.
+///
+/// Sorted runs of doubling lengths are merged pairwise, like in `BitonicMerge`:
+/// the first stage of each merge compares mirrored wires, later stages
+/// compare wires at halving distances. Every stage consists of `2^order / 2`
+/// comparators, each putting its minimum on its lower wire.
+#[derive(Clone, Debug)]
+pub struct BitonicSort {
+    length: usize,
+    block: usize,
+    distance: usize,
+    index: usize,
+}
+
+impl BitonicSort {
+    pub fn new(order: usize) -> Self {
+        Self { length: 1 << order, block: 2, distance: 1, index: 0 }
+    }
+}
+
+impl Iterator for BitonicSort {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.block <= self.length {
+            while self.distance > 0 {
+                let distance = self.distance;
+                while self.index < self.length {
+                    let min = self.index;
+                    self.index += 1;
+                    if (min % (2 * distance)) >= distance {
+                        continue;
+                    }
+                    let max = if 2 * distance == self.block {
+                        let offset = min % self.block;
+                        min - offset + self.block - 1 - offset
+                    } else {
+                        min + distance
+                    };
+                    return Some(Pair::new(min, max));
+                }
+                self.distance /= 2;
+                self.index = 0;
+            }
+            self.block *= 2;
+            self.distance = self.block / 2;
+        }
+        None
+    }
+}
+
+/// Iterator over the comparators of Parberry's pairwise sorting network
+/// for `2^order` wires, in flat form.
+///
+/// The first phase sorts wires pairwise, then pairs of pairs, and so on,
+/// comparing wires at doubling distances. The second phase merges the
+/// resulting runs, comparing wires at distances `d * a` for halving `a`.
+/// Its size and depth equal those of Batcher's odd-even merge sort.
+// I. Parberry, "The pairwise sorting network", Parallel Processing Letters 2(2-3), 1992
+#[derive(Clone, Debug)]
+pub struct PairwiseSort {
+    length: usize,
+    is_first_phase: bool,
+    a: usize,
+    d: usize,
+    e: usize,
+    index: usize,
+    run: usize,
+}
+
+impl PairwiseSort {
+    pub fn new(order: usize) -> Self {
+        Self {
+            length: 1 << order,
+            is_first_phase: true,
+            a: 1,
+            d: 0,
+            e: 0,
+            index: 1,
+            run: 0,
+        }
+    }
+}
+
+impl Iterator for PairwiseSort {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.index < self.length {
+                let distance = if self.is_first_phase { self.a } else { self.d * self.a };
+                let max = self.index;
+                // Comparators come in runs of `a`, separated by gaps of `a`:
+                self.index += 1;
+                self.run = (self.run + 1) % self.a;
+                if self.run == 0 {
+                    self.index += self.a;
+                }
+                return Some(Pair::new(max - distance, max));
+            }
+            if self.is_first_phase {
+                self.a *= 2;
+                if self.a < self.length {
+                    self.index = self.a;
+                    self.run = 0;
+                    continue;
+                }
+                self.is_first_phase = false;
+                self.a /= 4;
+                self.e = 1;
+                self.d = self.e;
+            } else {
+                self.d /= 2;
+                if self.d == 0 {
+                    self.a /= 2;
+                    self.e = 2 * self.e + 1;
+                    self.d = self.e;
+                }
+            }
+            if self.a == 0 {
+                return None;
+            }
+            self.index = (self.d + 1) * self.a;
+            self.run = 0;
+        }
+    }
+}
+
+/// Iterator over the comparators of Bose and Nelson's sorting network
+/// for an arbitrary number of wires, in flat form.
+///
+/// The wires are split into halves, which get sorted recursively,
+/// before being merged by recursively merging the halves of both runs.
+/// Unlike the other networks it is constructed natively for lengths
+/// that aren't powers of two, rather than pruned from a larger one.
+// R. C. Bose, R. J. Nelson, "A sorting problem", Journal of the ACM 9(2), 1962
+#[derive(Clone, Debug)]
+pub struct BoseNelsonSort {
+    tasks: [BoseNelsonTask; BOSE_NELSON_STACK],
+    len: usize,
+}
+
+// Each level of recursion leaves at most two tasks pending. For lengths below `2^32`
+// there are at most 32 levels of sorting, plus at most 34 minus those of merging,
+// so the stack of 20-byte tasks takes up less than 1.4 KiB:
+const BOSE_NELSON_STACK: usize = 2 * (u32::BITS as usize + 2) + 1;
+
+#[derive(Clone, Copy, Debug)]
+enum BoseNelsonTask {
+    Sort { start: u32, length: u32 },
+    Merge { lhs: u32, lhs_len: u32, rhs: u32, rhs_len: u32 },
+}
+
+impl BoseNelsonSort {
+    /// Panics if `length` doesn't fit into a `u32`.
+    pub fn new(length: usize) -> Self {
+        assert!(length <= u32::MAX as usize, "Expected length of at most {}", u32::MAX);
+        let mut tasks = [BoseNelsonTask::Sort { start: 0, length: 0 }; BOSE_NELSON_STACK];
+        tasks[0] = BoseNelsonTask::Sort { start: 0, length: length as u32 };
+        Self { tasks, len: 1 }
+    }
+
+    fn push(&mut self, task: BoseNelsonTask) {
+        self.tasks[self.len] = task;
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<BoseNelsonTask> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(self.tasks[self.len])
+    }
+}
+
+impl Iterator for BoseNelsonSort {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use self::BoseNelsonTask::*;
+
+        // Tasks are pushed in reverse, to be popped in order:
+        while let Some(task) = self.pop() {
+            match task {
+                Sort { length, .. } if length < 2 => {}
+                Sort { start, length } => {
+                    let half = length / 2;
+                    let (lhs, rhs) = (start, start + half);
+                    let (lhs_len, rhs_len) = (half, length - half);
+                    self.push(Merge { lhs, lhs_len, rhs, rhs_len });
+                    self.push(Sort { start: rhs, length: rhs_len });
+                    self.push(Sort { start: lhs, length: lhs_len });
+                }
+                Merge { lhs, lhs_len: 1, rhs, rhs_len: 1 } => {
+                    return Some(Pair::new(lhs as usize, rhs as usize));
+                }
+                Merge { lhs, lhs_len: 1, rhs, rhs_len: 2 } => {
+                    self.push(Merge { lhs, lhs_len: 1, rhs, rhs_len: 1 });
+                    return Some(Pair::new(lhs as usize, rhs as usize + 1));
+                }
+                Merge { lhs, lhs_len: 2, rhs, rhs_len: 1 } => {
+                    self.push(Merge { lhs: lhs + 1, lhs_len: 1, rhs, rhs_len: 1 });
+                    return Some(Pair::new(lhs as usize, rhs as usize));
+                }
+                Merge { lhs, lhs_len, rhs, rhs_len } => {
+                    let lhs_half = lhs_len / 2;
+                    let rhs_half = if lhs_len % 2 == 1 { rhs_len / 2 } else { rhs_len.div_ceil(2) };
+                    let (lhs_upper, lhs_upper_len) = (lhs + lhs_half, lhs_len - lhs_half);
+                    let (rhs_upper, rhs_upper_len) = (rhs + rhs_half, rhs_len - rhs_half);
+                    self.push(Merge {
+                        lhs: lhs_upper,
+                        lhs_len: lhs_upper_len,
+                        rhs,
+                        rhs_len: rhs_half,
+                    });
+                    self.push(Merge {
+                        lhs: lhs_upper,
+                        lhs_len: lhs_upper_len,
+                        rhs: rhs_upper,
+                        rhs_len: rhs_upper_len,
+                    });
+                    self.push(Merge { lhs, lhs_len: lhs_half, rhs, rhs_len: rhs_half });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over the comparators of Batcher's merge-exchange sort
+/// for an arbitrary number of wires, in flat form.
+///
+/// Follows Knuth's Algorithm M (TAOCP Vol. 3, 5.2.2), which handles
+/// lengths that aren't powers of two natively, rather than by pruning.
+#[derive(Clone, Debug)]
+pub struct MergeExchange {
+    length: usize,
+    top: usize,
+    p: usize,
+    q: usize,
+    r: usize,
+    d: usize,
+    index: usize,
+}
+
+impl MergeExchange {
+    pub fn new(length: usize) -> Self {
+        // M1: `top = 2^(t - 1)` for `t = ceil(lg(length))`
+        let top = length.next_power_of_two() / 2;
+        Self { length, top, p: top, q: top, r: 0, d: top, index: 0 }
+    }
+}
+
+impl Iterator for MergeExchange {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.p > 0 {
+            // M3: compare-exchange all `(i, i + d)` with `i & p == r`
+            while self.index + self.d < self.length {
+                let min = self.index;
+                self.index += 1;
+                if min & self.p == self.r {
+                    return Some(Pair::new(min, min + self.d));
+                }
+            }
+            self.index = 0;
+            if self.q != self.p {
+                // M4: next merge level
+                self.d = self.q - self.p;
+                self.q /= 2;
+                self.r = self.p;
+            } else {
+                // M5: next pass, M2: its first level
+                self.p /= 2;
+                self.q = self.top;
+                self.r = 0;
+                self.d = self.p;
+            }
+        }
+        None
+    }
+}
+
+/// Iterator over the comparators of the odd-even transposition sort
+/// for an arbitrary number of wires, in flat form.
+///
+/// Each of its `length` rounds compares all adjacent wires, starting at
+/// even wires in even rounds and at odd wires in odd rounds, like bricks in a wall.
+/// It takes `length * (length - 1) / 2` comparators in as many rounds as wires.
+#[derive(Clone, Debug)]
+pub struct OddEvenTransposition {
+    length: usize,
+    round: usize,
+    index: usize,
+}
+
+impl OddEvenTransposition {
+    pub fn new(length: usize) -> Self {
+        Self { length, round: 0, index: 0 }
+    }
+}
+
+impl Iterator for OddEvenTransposition {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.round < self.length {
+            if self.index + 1 < self.length {
+                let min = self.index;
+                self.index += 2;
+                return Some(Pair::new(min, min + 1));
+            }
+            self.round += 1;
+            self.index = self.round % 2;
+        }
+        None
+    }
+}
+
+/// Iterator over the comparators of Batcher's odd-even merge
+/// of two adjacent sorted runs of arbitrary lengths.
+///
+/// The runs are embedded into a power-of-two merge, with the first run
+/// padded at its front and the second run padded at its back. Comparators
+/// touching the padding are no-ops and thus skipped.
+#[derive(Clone, Debug)]
+pub struct OddEvenMerge {
+    window: Window,
+    distance: usize,
+    index: usize,
+}
+
+impl OddEvenMerge {
+    pub fn new(lhs: usize, rhs: usize) -> Self {
+        let window = Window::new(lhs, rhs);
+        let distance = window.half;
+        Self { window, distance, index: 0 }
+    }
+}
+
+impl Iterator for OddEvenMerge {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.distance > 0 {
+            let distance = self.distance;
+            let length = 2 * self.window.half;
+            while self.index + distance < length {
+                let min = self.index;
+                self.index += 1;
+                // The first stage compares the runs' elements pairwise,
+                // later stages only the upper halves of each `2 * distance` block:
+                let is_first = distance == self.window.half;
+                if !is_first && (min % (2 * distance)) < distance {
+                    continue;
+                }
+                if let Some(pair) = self.window.pair(min, min + distance) {
+                    return Some(pair);
+                }
+            }
+            self.distance /= 2;
+            self.index = 0;
+        }
+        None
+    }
+}
+
+/// Iterator over the comparators of a bitonic merge
+/// of two adjacent sorted runs of arbitrary lengths.
+///
+/// Instead of reversing the second run, the first stage compares
+/// mirrored wires, so that every comparator puts its minimum on its lower wire.
+/// Padding works as for `OddEvenMerge`.
+#[derive(Clone, Debug)]
+pub struct BitonicMerge {
+    window: Window,
+    distance: usize,
+    index: usize,
+}
+
+impl BitonicMerge {
+    pub fn new(lhs: usize, rhs: usize) -> Self {
+        let window = Window::new(lhs, rhs);
+        let distance = window.half;
+        Self { window, distance, index: 0 }
+    }
+}
+
+impl Iterator for BitonicMerge {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.distance > 0 {
+            let distance = self.distance;
+            let length = 2 * self.window.half;
+            while self.index < length {
+                let min = self.index;
+                self.index += 1;
+                if (min % (2 * distance)) >= distance {
+                    continue;
+                }
+                let max = if distance == self.window.half {
+                    length - 1 - min
+                } else {
+                    min + distance
+                };
+                if let Some(pair) = self.window.pair(min, max) {
+                    return Some(pair);
+                }
+            }
+            self.distance /= 2;
+            self.index = 0;
+        }
+        None
+    }
+}
+
+/// The wires of two runs embedded into a power-of-two merge.
+#[derive(Clone, Debug)]
+struct Window {
+    half: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Window {
+    fn new(lhs: usize, rhs: usize) -> Self {
+        if lhs == 0 || rhs == 0 {
+            // Nothing to merge:
+            return Self { half: 0, start: 0, end: 0 };
+        }
+        let half = lhs.max(rhs).next_power_of_two();
+        Self {
+            half,
+            start: half - lhs,
+            end: half + rhs,
+        }
+    }
+
+    fn pair(&self, min: usize, max: usize) -> Option<Pair> {
+        if min < self.start || max >= self.end {
+            return None;
+        }
+        Some(Pair::new(min - self.start, max - self.start))
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -0,0 +1,313 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// Curated tables of the best-known sorting networks for widths 2 to 16,
+// one row of comparators per layer, from which `build.rs` generates
+// `OptimalSortingNetwork<N>` and `DepthOptimalSortingNetwork<N>`.
+//
+// Every entry is checked against all inputs of zeros and ones by the crate's tests,
+// which also pin each entry's comparators and depth to the published values.
+//
+// The tables deliberately stop at 16. The best-known networks for widths 17 to 32
+// have been published, but haven't been transcribed and checked yet. Until they are,
+// there is no `OptimalSortingNetwork<N>` or `DepthOptimalSortingNetwork<N>` for them.
+
+/// Networks with as few comparators as known, for widths 2 to 16.
+///
+/// Sizes up to width 12 are proven optimal.
+const SIZE_OPTIMAL_NETWORKS: [&[&[(usize, usize)]]; 15] = [
+    // 2: 1 comparator (optimal), depth 1.
+    &[
+        &[(0, 1)],
+    ],
+    // 3: 3 comparators (optimal), depth 3.
+    &[
+        &[(0, 2)],
+        &[(0, 1)],
+        &[(1, 2)],
+    ],
+    // 4: 5 comparators (optimal), depth 3.
+    &[
+        &[(0, 2), (1, 3)],
+        &[(0, 1), (2, 3)],
+        &[(1, 2)],
+    ],
+    // 5: 9 comparators (optimal), depth 5.
+    &[
+        &[(0, 3), (1, 4)],
+        &[(0, 2), (1, 3)],
+        &[(0, 1), (2, 4)],
+        &[(1, 2), (3, 4)],
+        &[(2, 3)],
+    ],
+    // 6: 12 comparators (optimal), depth 5.
+    &[
+        &[(0, 5), (1, 3), (2, 4)],
+        &[(1, 2), (3, 4)],
+        &[(0, 3), (2, 5)],
+        &[(0, 1), (2, 3), (4, 5)],
+        &[(1, 2), (3, 4)],
+    ],
+    // 7: 16 comparators (optimal), depth 6.
+    &[
+        &[(0, 6), (2, 3), (4, 5)],
+        &[(0, 2), (1, 4), (3, 6)],
+        &[(0, 1), (2, 5), (3, 4)],
+        &[(1, 2), (4, 6)],
+        &[(2, 3), (4, 5)],
+        &[(1, 2), (3, 4), (5, 6)],
+    ],
+    // 8: 19 comparators (optimal), depth 6.
+    &[
+        &[(0, 2), (1, 3), (4, 6), (5, 7)],
+        &[(0, 4), (1, 5), (2, 6), (3, 7)],
+        &[(0, 1), (2, 3), (4, 5), (6, 7)],
+        &[(2, 4), (3, 5)],
+        &[(1, 4), (3, 6)],
+        &[(1, 2), (3, 4), (5, 6)],
+    ],
+    // 9: 25 comparators (optimal), depth 7.
+    &[
+        &[(0, 3), (1, 7), (2, 5), (4, 8)],
+        &[(0, 7), (2, 4), (3, 8), (5, 6)],
+        &[(0, 2), (1, 3), (4, 5), (7, 8)],
+        &[(1, 4), (3, 6), (5, 7)],
+        &[(0, 1), (2, 4), (3, 5), (6, 8)],
+        &[(2, 3), (4, 5), (6, 7)],
+        &[(1, 2), (3, 4), (5, 6)],
+    ],
+    // 10: 29 comparators (optimal), depth 8.
+    &[
+        &[(0, 8), (1, 9), (2, 7), (3, 5), (4, 6)],
+        &[(0, 2), (1, 4), (5, 8), (7, 9)],
+        &[(0, 3), (2, 4), (5, 7), (6, 9)],
+        &[(0, 1), (3, 6), (8, 9)],
+        &[(1, 5), (2, 3), (4, 8), (6, 7)],
+        &[(1, 2), (3, 5), (4, 6), (7, 8)],
+        &[(2, 3), (4, 5), (6, 7)],
+        &[(3, 4), (5, 6)],
+    ],
+    // 11: 35 comparators (optimal), depth 8.
+    &[
+        &[(0, 9), (1, 6), (2, 4), (3, 7), (5, 8)],
+        &[(0, 1), (3, 5), (4, 10), (6, 9), (7, 8)],
+        &[(1, 3), (2, 5), (4, 7), (8, 10)],
+        &[(0, 4), (1, 2), (3, 7), (5, 9), (6, 8)],
+        &[(0, 1), (2, 6), (4, 5), (7, 8), (9, 10)],
+        &[(2, 4), (3, 6), (5, 7), (8, 9)],
+        &[(1, 2), (3, 4), (5, 6), (7, 8)],
+        &[(2, 3), (4, 5), (6, 7)],
+    ],
+    // 12: 39 comparators (optimal), depth 9.
+    &[
+        &[(0, 8), (1, 7), (2, 6), (3, 11), (4, 10), (5, 9)],
+        &[(0, 1), (2, 5), (3, 4), (6, 9), (7, 8), (10, 11)],
+        &[(0, 2), (1, 6), (5, 10), (9, 11)],
+        &[(0, 3), (1, 2), (4, 6), (5, 7), (8, 11), (9, 10)],
+        &[(1, 4), (3, 5), (6, 8), (7, 10)],
+        &[(1, 3), (2, 5), (6, 9), (8, 10)],
+        &[(2, 3), (4, 5), (6, 7), (8, 9)],
+        &[(4, 6), (5, 7)],
+        &[(3, 4), (5, 6), (7, 8)],
+    ],
+    // 13: 45 comparators (best known), depth 10.
+    &[
+        &[(0, 12), (1, 10), (2, 9), (3, 7), (5, 11), (6, 8)],
+        &[(1, 6), (2, 3), (4, 11), (7, 9), (8, 10)],
+        &[(0, 4), (1, 2), (3, 6), (7, 8), (9, 10), (11, 12)],
+        &[(4, 6), (5, 9), (8, 11), (10, 12)],
+        &[(0, 5), (3, 8), (4, 7), (6, 11), (9, 10)],
+        &[(0, 1), (2, 5), (6, 9), (7, 8), (10, 11)],
+        &[(1, 3), (2, 4), (5, 6), (9, 10)],
+        &[(1, 2), (3, 4), (5, 7), (6, 8)],
+        &[(2, 3), (4, 5), (6, 7), (8, 9)],
+        &[(3, 4), (5, 6)],
+    ],
+    // 14: 51 comparators (best known), depth 10.
+    &[
+        &[(0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13)],
+        &[(0, 2), (1, 3), (4, 8), (5, 9), (10, 12), (11, 13)],
+        &[(0, 4), (1, 2), (3, 7), (5, 8), (6, 10), (9, 13), (11, 12)],
+        &[(0, 6), (1, 5), (3, 9), (4, 10), (7, 13), (8, 12)],
+        &[(2, 10), (3, 11), (4, 6), (7, 9)],
+        &[(1, 3), (2, 8), (5, 11), (6, 7), (10, 12)],
+        &[(1, 4), (2, 6), (3, 5), (7, 11), (8, 10), (9, 12)],
+        &[(2, 4), (3, 6), (5, 8), (7, 10), (9, 11)],
+        &[(3, 4), (5, 6), (7, 8), (9, 10)],
+        &[(6, 7)],
+    ],
+    // 15: 56 comparators (best known, Green's network minus its last wire), depth 10.
+    &[
+        &[(0, 13), (1, 12), (3, 14), (4, 8), (5, 6), (7, 11), (9, 10)],
+        &[(0, 5), (1, 7), (2, 9), (3, 4), (6, 13), (8, 14), (11, 12)],
+        &[(0, 1), (2, 3), (4, 5), (6, 8), (7, 9), (10, 11), (12, 13)],
+        &[(0, 2), (1, 3), (4, 10), (5, 11), (6, 7), (8, 9), (12, 14)],
+        &[(1, 2), (3, 12), (4, 6), (5, 7), (8, 10), (9, 11), (13, 14)],
+        &[(1, 4), (2, 6), (5, 8), (7, 10), (9, 13), (11, 14)],
+        &[(2, 4), (3, 6), (9, 12), (11, 13)],
+        &[(3, 5), (6, 8), (7, 9), (10, 12)],
+        &[(3, 4), (5, 6), (7, 8), (9, 10), (11, 12)],
+        &[(6, 7), (8, 9)],
+    ],
+    // 16: 60 comparators (best known, Green (1969)), depth 10.
+    &[
+        &[(0, 13), (1, 12), (2, 15), (3, 14), (4, 8), (5, 6), (7, 11), (9, 10)],
+        &[(0, 5), (1, 7), (2, 9), (3, 4), (6, 13), (8, 14), (10, 15), (11, 12)],
+        &[(0, 1), (2, 3), (4, 5), (6, 8), (7, 9), (10, 11), (12, 13), (14, 15)],
+        &[(0, 2), (1, 3), (4, 10), (5, 11), (6, 7), (8, 9), (12, 14), (13, 15)],
+        &[(1, 2), (3, 12), (4, 6), (5, 7), (8, 10), (9, 11), (13, 14)],
+        &[(1, 4), (2, 6), (5, 8), (7, 10), (9, 13), (11, 14)],
+        &[(2, 4), (3, 6), (9, 12), (11, 13)],
+        &[(3, 5), (6, 8), (7, 9), (10, 12)],
+        &[(3, 4), (5, 6), (7, 8), (9, 10), (11, 12)],
+        &[(6, 7), (8, 9)],
+    ],
+];
+
+/// Networks with as few layers as known, for widths 2 to 16.
+///
+/// All depths are proven optimal. Among networks of that depth,
+/// entries have as few comparators as we could find.
+const DEPTH_OPTIMAL_NETWORKS: [&[&[(usize, usize)]]; 15] = [
+    // 2: depth 1, 1 comparator (same as the size-optimal one).
+    &[
+        &[(0, 1)],
+    ],
+    // 3: depth 3, 3 comparators (same as the size-optimal one).
+    &[
+        &[(0, 2)],
+        &[(0, 1)],
+        &[(1, 2)],
+    ],
+    // 4: depth 3, 5 comparators (same as the size-optimal one).
+    &[
+        &[(0, 2), (1, 3)],
+        &[(0, 1), (2, 3)],
+        &[(1, 2)],
+    ],
+    // 5: depth 5, 9 comparators (same as the size-optimal one).
+    &[
+        &[(0, 3), (1, 4)],
+        &[(0, 2), (1, 3)],
+        &[(0, 1), (2, 4)],
+        &[(1, 2), (3, 4)],
+        &[(2, 3)],
+    ],
+    // 6: depth 5, 12 comparators (same as the size-optimal one).
+    &[
+        &[(0, 5), (1, 3), (2, 4)],
+        &[(1, 2), (3, 4)],
+        &[(0, 3), (2, 5)],
+        &[(0, 1), (2, 3), (4, 5)],
+        &[(1, 2), (3, 4)],
+    ],
+    // 7: depth 6, 16 comparators (same as the size-optimal one).
+    &[
+        &[(0, 6), (2, 3), (4, 5)],
+        &[(0, 2), (1, 4), (3, 6)],
+        &[(0, 1), (2, 5), (3, 4)],
+        &[(1, 2), (4, 6)],
+        &[(2, 3), (4, 5)],
+        &[(1, 2), (3, 4), (5, 6)],
+    ],
+    // 8: depth 6, 19 comparators (same as the size-optimal one).
+    &[
+        &[(0, 2), (1, 3), (4, 6), (5, 7)],
+        &[(0, 4), (1, 5), (2, 6), (3, 7)],
+        &[(0, 1), (2, 3), (4, 5), (6, 7)],
+        &[(2, 4), (3, 5)],
+        &[(1, 4), (3, 6)],
+        &[(1, 2), (3, 4), (5, 6)],
+    ],
+    // 9: depth 7, 25 comparators (same as the size-optimal one).
+    &[
+        &[(0, 3), (1, 7), (2, 5), (4, 8)],
+        &[(0, 7), (2, 4), (3, 8), (5, 6)],
+        &[(0, 2), (1, 3), (4, 5), (7, 8)],
+        &[(1, 4), (3, 6), (5, 7)],
+        &[(0, 1), (2, 4), (3, 5), (6, 8)],
+        &[(2, 3), (4, 5), (6, 7)],
+        &[(1, 2), (3, 4), (5, 6)],
+    ],
+    // 10: depth 7, 31 comparators (best known for this depth).
+    &[
+        &[(0, 1), (2, 5), (3, 6), (4, 7), (8, 9)],
+        &[(0, 6), (1, 8), (2, 4), (3, 9), (5, 7)],
+        &[(0, 2), (1, 3), (4, 5), (6, 8), (7, 9)],
+        &[(0, 1), (2, 7), (3, 5), (4, 6), (8, 9)],
+        &[(1, 2), (3, 4), (5, 6), (7, 8)],
+        &[(1, 3), (2, 4), (5, 7), (6, 8)],
+        &[(2, 3), (4, 5), (6, 7)],
+    ],
+    // 11: depth 8, 35 comparators (same as the size-optimal one).
+    &[
+        &[(0, 9), (1, 6), (2, 4), (3, 7), (5, 8)],
+        &[(0, 1), (3, 5), (4, 10), (6, 9), (7, 8)],
+        &[(1, 3), (2, 5), (4, 7), (8, 10)],
+        &[(0, 4), (1, 2), (3, 7), (5, 9), (6, 8)],
+        &[(0, 1), (2, 6), (4, 5), (7, 8), (9, 10)],
+        &[(2, 4), (3, 6), (5, 7), (8, 9)],
+        &[(1, 2), (3, 4), (5, 6), (7, 8)],
+        &[(2, 3), (4, 5), (6, 7)],
+    ],
+    // 12: depth 8, 40 comparators (best known for this depth).
+    &[
+        &[(0, 8), (1, 7), (2, 6), (3, 11), (4, 10), (5, 9)],
+        &[(0, 1), (2, 5), (3, 4), (6, 9), (7, 8), (10, 11)],
+        &[(2, 3), (4, 6), (5, 10), (9, 11)],
+        &[(0, 4), (1, 6), (3, 5), (7, 9), (8, 10)],
+        &[(0, 2), (1, 3), (4, 8), (5, 7), (6, 9), (10, 11)],
+        &[(1, 2), (3, 7), (4, 5), (6, 8), (9, 10)],
+        &[(2, 4), (3, 5), (6, 7), (8, 9)],
+        &[(1, 2), (3, 4), (5, 6), (7, 8)],
+    ],
+    // 13: depth 9, 47 comparators (the depth-optimal network for width 16 minus its upper wires).
+    &[
+        &[(0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11)],
+        &[(0, 2), (1, 3), (4, 6), (5, 7), (8, 10), (9, 11)],
+        &[(0, 4), (1, 5), (2, 6), (3, 7), (8, 12)],
+        &[(0, 8), (1, 9), (2, 10), (3, 11), (4, 12)],
+        &[(1, 4), (2, 8), (3, 5), (6, 10), (9, 12)],
+        &[(3, 10), (4, 8), (5, 9), (6, 12), (7, 11)],
+        &[(1, 2), (3, 5), (6, 8), (7, 12), (9, 10)],
+        &[(2, 4), (3, 6), (5, 8), (7, 9), (10, 12)],
+        &[(3, 4), (5, 6), (7, 8), (9, 10), (11, 12)],
+    ],
+    // 14: depth 9, 52 comparators (the depth-optimal network for width 16 minus its upper wires).
+    &[
+        &[(0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13)],
+        &[(0, 2), (1, 3), (4, 6), (5, 7), (8, 10), (9, 11)],
+        &[(0, 4), (1, 5), (2, 6), (3, 7), (8, 12), (9, 13)],
+        &[(0, 8), (1, 9), (2, 10), (3, 11), (4, 12), (5, 13)],
+        &[(1, 4), (2, 8), (3, 5), (6, 10), (9, 12), (11, 13)],
+        &[(3, 10), (4, 8), (5, 9), (6, 12), (7, 11)],
+        &[(1, 2), (3, 5), (6, 8), (7, 12), (9, 10)],
+        &[(2, 4), (3, 6), (5, 8), (7, 9), (10, 12), (11, 13)],
+        &[(3, 4), (5, 6), (7, 8), (9, 10), (11, 12)],
+    ],
+    // 15: depth 9, 57 comparators (the depth-optimal network for width 16 minus its upper wires).
+    &[
+        &[(0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13)],
+        &[(0, 2), (1, 3), (4, 6), (5, 7), (8, 10), (9, 11), (12, 14)],
+        &[(0, 4), (1, 5), (2, 6), (3, 7), (8, 12), (9, 13), (10, 14)],
+        &[(0, 8), (1, 9), (2, 10), (3, 11), (4, 12), (5, 13), (6, 14)],
+        &[(1, 4), (2, 8), (3, 5), (6, 10), (7, 14), (9, 12), (11, 13)],
+        &[(3, 10), (4, 8), (5, 9), (6, 12), (7, 11), (13, 14)],
+        &[(1, 2), (3, 5), (6, 8), (7, 12), (9, 10)],
+        &[(2, 4), (3, 6), (5, 8), (7, 9), (10, 12), (11, 13)],
+        &[(3, 4), (5, 6), (7, 8), (9, 10), (11, 12)],
+    ],
+    // 16: depth 9, 61 comparators (best known for this depth).
+    &[
+        &[(0, 1), (2, 3), (4, 5), (6, 7), (8, 9), (10, 11), (12, 13), (14, 15)],
+        &[(0, 2), (1, 3), (4, 6), (5, 7), (8, 10), (9, 11), (12, 14), (13, 15)],
+        &[(0, 4), (1, 5), (2, 6), (3, 7), (8, 12), (9, 13), (10, 14), (11, 15)],
+        &[(0, 8), (1, 9), (2, 10), (3, 11), (4, 12), (5, 13), (6, 14), (7, 15)],
+        &[(1, 4), (2, 8), (3, 5), (6, 10), (7, 14), (9, 12), (11, 13)],
+        &[(3, 10), (4, 8), (5, 9), (6, 12), (7, 11), (13, 14)],
+        &[(1, 2), (3, 5), (6, 8), (7, 12), (9, 10)],
+        &[(2, 4), (3, 6), (5, 8), (7, 9), (10, 12), (11, 13)],
+        &[(3, 4), (5, 6), (7, 8), (9, 10), (11, 12)],
+    ],
+];
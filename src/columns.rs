@@ -0,0 +1,68 @@
+//! Columns that can be co-sorted alongside a slice of keys.
+
+/// Trait for sets of equally long columns whose rows can be exchanged
+///
+/// Implemented for slices, mutable references to columns,
+/// as well as tuples of up to eight columns (which may be nested).
+pub trait Columns {
+    /// Returns `true` if every column has exactly `rows` rows.
+    fn has_rows(&self, rows: usize) -> bool;
+
+    /// Exchanges the rows `lhs` and `rhs` in every column.
+    fn swap_rows(&mut self, lhs: usize, rhs: usize);
+}
+
+impl<T> Columns for [T] {
+    #[inline]
+    fn has_rows(&self, rows: usize) -> bool {
+        self.len() == rows
+    }
+
+    #[inline]
+    fn swap_rows(&mut self, lhs: usize, rhs: usize) {
+        self.swap(lhs, rhs)
+    }
+}
+
+impl<C> Columns for &mut C
+where
+    C: Columns + ?Sized,
+{
+    #[inline]
+    fn has_rows(&self, rows: usize) -> bool {
+        (**self).has_rows(rows)
+    }
+
+    #[inline]
+    fn swap_rows(&mut self, lhs: usize, rhs: usize) {
+        (**self).swap_rows(lhs, rhs)
+    }
+}
+
+macro_rules! impl_columns_for_tuple {
+    ($($name:ident: $index:tt),*) => {
+        impl<$($name),*> Columns for ($($name,)*)
+        where
+            $($name: Columns),*
+        {
+            #[inline]
+            fn has_rows(&self, rows: usize) -> bool {
+                true $(&& self.$index.has_rows(rows))*
+            }
+
+            #[inline]
+            fn swap_rows(&mut self, lhs: usize, rhs: usize) {
+                $(self.$index.swap_rows(lhs, rhs);)*
+            }
+        }
+    }
+}
+
+impl_columns_for_tuple!(A: 0);
+impl_columns_for_tuple!(A: 0, B: 1);
+impl_columns_for_tuple!(A: 0, B: 1, C: 2);
+impl_columns_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_columns_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_columns_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_columns_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_columns_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
@@ -0,0 +1,79 @@
+//! Sorting of integer arrays in `const` contexts.
+//!
+//! Trait methods can't be called from a `const fn` (yet), so these functions
+//! walk the generated comparators (`FixedSizeSortingNetwork::PAIRS`) directly,
+//! one function per primitive integer type:
+//!
+//! ```
+//! use sorting_networks::sort_u32_array;
+//!
+//! const TABLE: [u32; 8] = sort_u32_array([7, 3, 5, 1, 6, 2, 4, 0]);
+//! assert_eq!(TABLE, [0, 1, 2, 3, 4, 5, 6, 7]);
+//! ```
+
+use {FixedSizeSortingNetwork, SortingNetwork};
+
+macro_rules! impl_const_sort {
+    ($($name:ident: $type:ident),*) => {
+        $(
+            #[doc = concat!("Sorts an array of `", stringify!($type), "`s, usable in `const` contexts")]
+            ///
+            /// Applies the same comparators as `SortingNetwork<N>`,
+            /// hence is available for every `N` from `2` up to `64`
+            /// (or up to `256` with the `wide-networks` feature).
+            pub const fn $name<const N: usize>(mut array: [$type; N]) -> [$type; N]
+            where
+                SortingNetwork<N>: FixedSizeSortingNetwork,
+            {
+                let pairs = <SortingNetwork<N> as FixedSizeSortingNetwork>::PAIRS;
+                let mut index = 0;
+                while index < pairs.len() {
+                    let (min, max) = (pairs[index].0 as usize, pairs[index].1 as usize);
+                    if array[min] > array[max] {
+                        let swap = array[min];
+                        array[min] = array[max];
+                        array[max] = swap;
+                    }
+                    index += 1;
+                }
+                array
+            }
+        )*
+    }
+}
+
+impl_const_sort!(
+    sort_u8_array: u8,
+    sort_u16_array: u16,
+    sort_u32_array: u32,
+    sort_u64_array: u64,
+    sort_u128_array: u128,
+    sort_usize_array: usize,
+    sort_i8_array: i8,
+    sort_i16_array: i16,
+    sort_i32_array: i32,
+    sort_i64_array: i64,
+    sort_i128_array: i128,
+    sort_isize_array: isize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNSIGNED: [u32; 16] = sort_u32_array([9, 4, 15, 0, 12, 7, 3, 11, 1, 14, 6, 10, 2, 13, 5, 8]);
+    const SIGNED: [i8; 5] = sort_i8_array([3, -128, 0, 127, -1]);
+    const WIDE: [u128; 3] = sort_u128_array([u128::MAX, 0, 1 << 100]);
+
+    #[test]
+    fn const_sort() {
+        let expected: Vec<u32> = (0..16).collect();
+        assert_eq!(&UNSIGNED[..], &expected[..]);
+        assert_eq!(SIGNED, [-128, -1, 0, 3, 127]);
+        assert_eq!(WIDE, [0, 1 << 100, u128::MAX]);
+
+        let items: [usize; 64] = std::array::from_fn(|index| (index * 37) % 64);
+        let expected: [usize; 64] = std::array::from_fn(|index| index);
+        assert_eq!(sort_usize_array(items), expected);
+    }
+}
@@ -3,9 +3,8 @@ use std::prelude::v1::*;
 
 use generate::*;
 
-pub fn debug_fmt(order: usize, f: &mut fmt::Formatter) -> fmt::Result {
-    let length = 1 << order;
-
+#[allow(clippy::let_and_return, clippy::needless_range_loop)]
+pub fn debug_fmt(order: usize, length: usize, f: &mut fmt::Formatter) -> fmt::Result {
     let group_widths: Vec<usize> = (0..order)
         .map(|group| {
             let stage_count = group + 1;
@@ -29,6 +28,9 @@ pub fn debug_fmt(order: usize, f: &mut fmt::Formatter) -> fmt::Result {
             for (index, stage) in block.enumerate() {
                 for pattern in stage {
                     for (index, pair) in pattern.enumerate() {
+                        if pair.max >= length {
+                            continue;
+                        }
                         let x = x + index;
                         for y in (pair.min)..(pair.max + 1) {
                             let character = if y == pair.min {
@@ -55,3 +57,43 @@ pub fn debug_fmt(order: usize, f: &mut fmt::Formatter) -> fmt::Result {
     }
     result
 }
+
+/// Draws a network given as a flat list of comparators,
+/// placing each comparator in the leftmost column where it
+/// overlaps neither other comparators nor their vertical lines.
+pub fn debug_fmt_pairs(pairs: &[(u8, u8)], length: usize, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut columns: Vec<usize> = vec![];
+    // The first free column on each wire:
+    let mut free = vec![0; length];
+    for &(min, max) in pairs {
+        let (min, max) = (min as usize, max as usize);
+        let column = free[min..=max].iter().cloned().max().unwrap_or(0);
+        for free in &mut free[min..=max] {
+            *free = column + 1;
+        }
+        columns.push(column);
+    }
+    let width = free.iter().cloned().max().unwrap_or(0);
+
+    let mut wires: Vec<Vec<char>> = vec![vec!['─'; width * 2 + 1]; length];
+    for (&(min, max), &x) in pairs.iter().zip(columns.iter()) {
+        let (min, max) = (min as usize, max as usize);
+        for (y, wire) in wires.iter_mut().enumerate().take(max + 1).skip(min) {
+            let character = if y == min {
+                '┰'
+            } else if y == max {
+                '┸'
+            } else {
+                '╂'
+            };
+            wire[x * 2 + 1] = character;
+        }
+    }
+
+    let mut result = Ok(());
+    for (index, wire) in wires.into_iter().enumerate() {
+        let string: String = wire.into_iter().collect();
+        result = writeln!(f, "{:3}: {}", index, string);
+    }
+    result
+}
@@ -0,0 +1,114 @@
+//! Compare-exchange primitives, which sorting networks are built from.
+
+use std::cmp::Ordering;
+
+/// Trait for compare-exchange primitives
+///
+/// A sorting network applies its primitive to each of its comparators,
+/// in order, passing the comparator's `(min, max)` wire indices.
+pub trait CompareExchange<T> {
+    /// Orders the elements at `min` and `max`,
+    /// such that `slice[min]` is not greater than `slice[max]` afterwards.
+    fn compare_exchange(&mut self, slice: &mut [T], min: usize, max: usize);
+}
+
+impl<T, C> CompareExchange<T> for &mut C
+where
+    C: CompareExchange<T> + ?Sized,
+{
+    #[inline]
+    fn compare_exchange(&mut self, slice: &mut [T], min: usize, max: usize) {
+        (**self).compare_exchange(slice, min, max)
+    }
+}
+
+/// Compare-exchange primitive based on a comparator function
+#[derive(Clone, Copy, Debug)]
+pub struct Comparator<F> {
+    compare: F,
+}
+
+impl<F> Comparator<F> {
+    /// Creates a primitive from a comparator function.
+    #[inline]
+    pub fn new(compare: F) -> Self {
+        Self { compare }
+    }
+}
+
+impl<T, F> CompareExchange<T> for Comparator<F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    #[inline]
+    fn compare_exchange(&mut self, slice: &mut [T], min: usize, max: usize) {
+        if (self.compare)(&slice[min], &slice[max]) == Ordering::Greater {
+            slice.swap(min, max);
+        }
+    }
+}
+
+/// Compare-exchange primitive based on `min`/`max`
+///
+/// For primitive integers this compiles down to branchless
+/// conditional moves on most targets.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MinMax;
+
+impl MinMax {
+    /// Creates a min/max-based primitive.
+    #[inline]
+    pub fn new() -> Self {
+        MinMax
+    }
+}
+
+impl<T> CompareExchange<T> for MinMax
+where
+    T: Ord + Copy,
+{
+    #[inline]
+    fn compare_exchange(&mut self, slice: &mut [T], min: usize, max: usize) {
+        let (lhs, rhs) = (slice[min], slice[max]);
+        slice[min] = lhs.min(rhs);
+        slice[max] = lhs.max(rhs);
+    }
+}
+
+/// Compare-exchange primitive counting the invocations of another primitive
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Counting<C> {
+    inner: C,
+    count: usize,
+}
+
+impl<C> Counting<C> {
+    /// Creates a counting primitive wrapping `inner`.
+    #[inline]
+    pub fn new(inner: C) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    /// The number of compare-exchanges performed so far
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the wrapped primitive.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<T, C> CompareExchange<T> for Counting<C>
+where
+    C: CompareExchange<T>,
+{
+    #[inline]
+    fn compare_exchange(&mut self, slice: &mut [T], min: usize, max: usize) {
+        self.count += 1;
+        self.inner.compare_exchange(slice, min, max)
+    }
+}
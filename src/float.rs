@@ -0,0 +1,229 @@
+//! Sorting of floating-point numbers, which lack a total order through `Ord`.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use exchange::CompareExchange;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Trait for the primitive floating-point types `f32` and `f64`
+pub trait Float: Copy + PartialOrd + sealed::Sealed {
+    /// Compares by IEEE 754's `totalOrder` predicate (see `f64::total_cmp`).
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    /// Returns `true` if the value is NaN.
+    fn is_nan(self) -> bool;
+
+    /// Orders `min` and `max` by `total_cmp`, such that `*min <= *max` afterwards,
+    /// selecting both from a single comparison of their bits.
+    fn total_exchange(min: &mut Self, max: &mut Self);
+}
+
+macro_rules! impl_float {
+    ($($type:ident as $signed:ident, $unsigned:ident),*) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl Float for $type {
+                #[inline]
+                fn total_cmp(&self, other: &Self) -> Ordering {
+                    <$type>::total_cmp(self, other)
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    <$type>::is_nan(self)
+                }
+
+                #[inline]
+                fn total_exchange(min: &mut Self, max: &mut Self) {
+                    // Flipping all but the sign bit of negative values orders their bits
+                    // as signed integers like `total_cmp` orders the floats (see `f64::total_cmp`):
+                    let key = |value: $type| {
+                        let bits = value.to_bits() as $signed;
+                        bits ^ ((((bits >> (<$signed>::BITS - 1)) as $unsigned) >> 1) as $signed)
+                    };
+                    let (lhs, rhs) = (*min, *max);
+                    // Both outputs are selected by the same comparison,
+                    // so they always remain a permutation of the inputs:
+                    let is_greater = key(lhs) > key(rhs);
+                    *min = if is_greater { rhs } else { lhs };
+                    *max = if is_greater { lhs } else { rhs };
+                }
+            }
+        )*
+    }
+}
+
+impl_float!(f32 as i32, u32, f64 as i64, u64);
+
+/// Placement of NaNs when sorting floats
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// NaNs are moved to the front, ahead of all numbers.
+    First,
+    /// NaNs are moved to the back, behind all numbers.
+    Last,
+    /// NaNs are rejected, leaving the slice untouched.
+    Error,
+}
+
+/// Error returned when sorting floats containing NaNs with `NanPolicy::Error`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NanError {
+    index: usize,
+}
+
+impl NanError {
+    /// The index of the first NaN in the rejected slice
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for NanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "slice contains NaN at index {}", self.index)
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl ::std::error::Error for NanError {}
+
+/// Returns a comparator ordering floats by `total_cmp`,
+/// with all NaNs (regardless of their sign) placed as per `policy`
+///
+/// `NanPolicy::Error` is treated like `NanPolicy::Last`.
+pub(crate) fn nan_cmp<T>(policy: NanPolicy) -> impl Fn(&T, &T) -> Ordering
+where
+    T: Float,
+{
+    move |lhs: &T, rhs: &T| {
+        let nans = lhs.is_nan().cmp(&rhs.is_nan());
+        let nans = if policy == NanPolicy::First {
+            nans.reverse()
+        } else {
+            nans
+        };
+        nans.then_with(|| lhs.total_cmp(rhs))
+    }
+}
+
+/// Returns an error pointing at the first NaN in `slice`, if any.
+pub(crate) fn find_nan<T>(slice: &[T]) -> Result<(), NanError>
+where
+    T: Float,
+{
+    match slice.iter().position(|item| item.is_nan()) {
+        Some(index) => Err(NanError { index }),
+        None => Ok(()),
+    }
+}
+
+/// Branchless compare-exchange primitive for floats, ordering them by `total_cmp`
+///
+/// Both outputs are selected from a single comparison of the values' bits,
+/// which compiles down to conditional moves on most targets.
+/// Unlike `f64::min`/`f64::max`, which may return the same zero for `-0.0` and `+0.0`,
+/// this always leaves the slice a permutation of its input, with `-0.0` before `+0.0`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FloatMinMax;
+
+impl FloatMinMax {
+    /// Creates a min/max-based primitive for floats.
+    #[inline]
+    pub fn new() -> Self {
+        FloatMinMax
+    }
+}
+
+impl<T> CompareExchange<T> for FloatMinMax
+where
+    T: Float,
+{
+    #[inline]
+    fn compare_exchange(&mut self, slice: &mut [T], min: usize, max: usize) {
+        let (mut lhs, mut rhs) = (slice[min], slice[max]);
+        T::total_exchange(&mut lhs, &mut rhs);
+        slice[min] = lhs;
+        slice[max] = rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+    use super::*;
+    use {RuntimeSortingNetwork, SortingNetwork, SortingNetworkTrait};
+
+    fn bits(items: &[f64]) -> Vec<u64> {
+        items.iter().map(|item| item.to_bits()).collect()
+    }
+
+    #[test]
+    fn sort_floats() {
+        let nan = f64::NAN;
+        let items = [3.5, -nan, 0.0, f64::INFINITY, nan, -0.0, -1.0, f64::NEG_INFINITY];
+        let network = SortingNetwork::<8>::new();
+
+        let mut total = items;
+        network.sort_floats_total(&mut total[..]);
+        let expected = [-nan, f64::NEG_INFINITY, -1.0, -0.0, 0.0, 3.5, f64::INFINITY, nan];
+        assert_eq!(bits(&total), bits(&expected));
+
+        let mut first = items;
+        assert_eq!(network.sort_floats(&mut first[..], NanPolicy::First), Ok(()));
+        let expected = [-nan, nan, f64::NEG_INFINITY, -1.0, -0.0, 0.0, 3.5, f64::INFINITY];
+        assert_eq!(bits(&first), bits(&expected));
+
+        let mut last = items;
+        assert_eq!(network.sort_floats(&mut last[..], NanPolicy::Last), Ok(()));
+        let expected = [f64::NEG_INFINITY, -1.0, -0.0, 0.0, 3.5, f64::INFINITY, -nan, nan];
+        assert_eq!(bits(&last), bits(&expected));
+
+        let mut error = items;
+        let result = network.sort_floats(&mut error[..], NanPolicy::Error);
+        assert_eq!(result.map_err(|error| error.index()), Err(1));
+        assert_eq!(bits(&error), bits(&items));
+        let error: Box<dyn std::error::Error> = Box::new(result.unwrap_err());
+        assert!(error.source().is_none());
+
+        // Zeros of either sign must neither be merged nor reordered:
+        for policy in &[NanPolicy::First, NanPolicy::Last, NanPolicy::Error] {
+            let mut zeros = [0.0, -0.0];
+            assert_eq!(SortingNetwork::<2>::new().sort_floats(&mut zeros[..], *policy), Ok(()));
+            assert_eq!(bits(&zeros), bits(&[-0.0, 0.0]));
+
+            let mut zeros = [-0.0, 0.0, 1.0];
+            assert_eq!(RuntimeSortingNetwork::new().sort_floats(&mut zeros[..], *policy), Ok(()));
+            assert_eq!(bits(&zeros), bits(&[-0.0, 0.0, 1.0]));
+        }
+    }
+
+    #[test]
+    fn sort_floats_without_nans() {
+        for length in 0..40 {
+            let mut items: Vec<f32> = (0..length)
+                .map(|index| ((index * 313373) % length) as f32 - 20.0)
+                .collect();
+            let result = RuntimeSortingNetwork::new().sort_floats(&mut items[..], NanPolicy::Error);
+            assert_eq!(result, Ok(()));
+            let expected: Vec<f32> = (0..length).map(|index| index as f32 - 20.0).collect();
+            assert_eq!(items, expected);
+        }
+
+        for length in 0..40 {
+            let mut items: Vec<f64> = (0..length)
+                .map(|index| if (index * 313373) % 3 == 0 { -0.0 } else { 0.0 })
+                .collect();
+            let negative = items.iter().filter(|item| item.is_sign_negative()).count();
+            let result = RuntimeSortingNetwork::new().sort_floats(&mut items[..], NanPolicy::Error);
+            assert_eq!(result, Ok(()));
+            let expected: Vec<f64> = (0..length).map(|index| if index < negative { -0.0 } else { 0.0 }).collect();
+            assert_eq!(bits(&items), bits(&expected));
+        }
+    }
+}
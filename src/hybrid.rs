@@ -0,0 +1,253 @@
+//! Sorting of slices of arbitrary length, with sorting networks at the leaves.
+
+use std::cmp::Ordering;
+
+use median::{FixedSizeMedianNetwork, Median};
+use small::sort_small_by;
+
+/// Slices up to this length are sorted by a generated sorting network.
+const LEAF_WIDTH: usize = 32;
+
+/// Slices longer than this pick their pivot from nine samples, instead of three.
+const NINTHER_WIDTH: usize = 128;
+
+/// Sorts the passed slice
+///
+/// See `network_sort_by` for details.
+pub fn network_sort<T>(slice: &mut [T])
+where
+    T: Ord,
+{
+    network_sort_by(slice, |lhs, rhs| lhs.cmp(rhs))
+}
+
+/// Sorts the passed slice with a comparator function
+///
+/// This is an introspective quicksort whose partitions of up to 32 elements
+/// get sorted by the generated networks `SortingNetwork2` … `SortingNetwork32`.
+/// Pivots are picked by the median networks `Median<3>` and `Median<9>`.
+/// Should the recursion get too deep, the sort falls back to heapsort,
+/// which guarantees `O(n log n)` comparisons in the worst case.
+///
+/// Like `slice::sort_unstable_by` it neither allocates nor preserves
+/// the order of equal elements.
+pub fn network_sort_by<T, F>(slice: &mut [T], compare: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let limit = 2 * (usize::BITS - slice.len().leading_zeros()) as usize;
+    quicksort(slice, &compare, limit);
+}
+
+/// Extension trait for sorting slices with `network_sort`
+pub trait SliceExt<T> {
+    /// Sorts the slice, see `network_sort`.
+    fn network_sort(&mut self)
+    where
+        T: Ord;
+
+    /// Sorts the slice with a comparator function, see `network_sort_by`.
+    fn network_sort_by<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering;
+}
+
+impl<T> SliceExt<T> for [T] {
+    #[inline]
+    fn network_sort(&mut self)
+    where
+        T: Ord,
+    {
+        network_sort(self)
+    }
+
+    #[inline]
+    fn network_sort_by<F>(&mut self, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        network_sort_by(self, compare)
+    }
+}
+
+fn quicksort<T, F>(mut slice: &mut [T], compare: &F, mut limit: usize)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    loop {
+        let len = slice.len();
+        if len <= LEAF_WIDTH {
+            sort_small_by(slice, compare).unwrap();
+            return;
+        }
+        if limit == 0 {
+            heapsort(slice, compare);
+            return;
+        }
+        limit -= 1;
+
+        choose_pivot(slice, compare);
+        let mid = partition(slice, compare);
+
+        // Recurse into the shorter side, iterate on the longer one:
+        let (lhs, rhs) = slice.split_at_mut(mid);
+        let rhs = &mut rhs[1..];
+        if lhs.len() < rhs.len() {
+            quicksort(lhs, compare, limit);
+            slice = rhs;
+        } else {
+            quicksort(rhs, compare, limit);
+            slice = lhs;
+        }
+    }
+}
+
+/// Moves the median of evenly spread samples to the front of the slice.
+fn choose_pivot<T, F>(slice: &mut [T], compare: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    let pivot = if len > NINTHER_WIDTH {
+        // Splits `(2 * index + 1) * len / 18`, which overflows for huge slices of zero-sized types:
+        let samples: [usize; 9] = std::array::from_fn(|index| {
+            len / 18 * (2 * index + 1) + len % 18 * (2 * index + 1) / 18
+        });
+        median_of_samples::<_, _, Median<9>>(slice, &samples, compare)
+    } else {
+        let samples = [len / 4, len / 2, 3 * len / 4];
+        median_of_samples::<_, _, Median<3>>(slice, &samples, compare)
+    };
+    slice.swap(0, pivot);
+}
+
+/// Runs the median network `M` on the samples, returning the median's index.
+fn median_of_samples<T, F, M>(slice: &mut [T], samples: &[usize], compare: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+    M: FixedSizeMedianNetwork,
+{
+    for &(min, max) in M::pairs() {
+        let (min, max) = (samples[min as usize], samples[max as usize]);
+        if compare(&slice[min], &slice[max]) == Ordering::Greater {
+            slice.swap(min, max);
+        }
+    }
+    samples[M::WIDTH / 2]
+}
+
+/// Partitions the slice around its first element, returning the pivot's final index.
+///
+/// Elements equal to the pivot may end up on either side,
+/// which keeps partitions balanced for slices with many duplicates.
+fn partition<T, F>(slice: &mut [T], compare: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mid = {
+        let (pivot, rest) = slice.split_at_mut(1);
+        let pivot = &pivot[0];
+        let (mut lhs, mut rhs) = (0, rest.len());
+        loop {
+            while lhs < rhs && compare(&rest[lhs], pivot) == Ordering::Less {
+                lhs += 1;
+            }
+            while lhs < rhs && compare(&rest[rhs - 1], pivot) == Ordering::Greater {
+                rhs -= 1;
+            }
+            if lhs >= rhs {
+                break;
+            }
+            rhs -= 1;
+            rest.swap(lhs, rhs);
+            lhs += 1;
+        }
+        rhs
+    };
+    slice.swap(0, mid);
+    mid
+}
+
+fn heapsort<T, F>(slice: &mut [T], compare: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let sift_down = |slice: &mut [T], mut node: usize| loop {
+        let mut child = 2 * node + 1;
+        if child >= slice.len() {
+            break;
+        }
+        if child + 1 < slice.len() && compare(&slice[child], &slice[child + 1]) == Ordering::Less {
+            child += 1;
+        }
+        if compare(&slice[node], &slice[child]) != Ordering::Less {
+            break;
+        }
+        slice.swap(node, child);
+        node = child;
+    };
+
+    let len = slice.len();
+    for node in (0..(len / 2)).rev() {
+        sift_down(slice, node);
+    }
+    for end in (1..len).rev() {
+        slice.swap(0, end);
+        sift_down(&mut slice[..end], 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+    use super::*;
+
+    fn random(length: usize, modulus: u64) -> Vec<u64> {
+        let mut state = 0x2545_F491_4F6C_DD1D_u64;
+        (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state % modulus
+            })
+            .collect()
+    }
+
+    #[test]
+    fn network_sort() {
+        for &length in &[0, 1, 2, 31, 32, 33, 100, 129, 1000, 10_000] {
+            for &modulus in &[1, 2, 10, u64::MAX] {
+                let mut items = random(length, modulus);
+                let mut expected = items.clone();
+                expected.sort();
+                items.network_sort();
+                assert_eq!(items, expected);
+
+                items.reverse();
+                items.network_sort_by(|lhs, rhs| rhs.cmp(lhs));
+                expected.reverse();
+                assert_eq!(items, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn heapsort() {
+        for &length in &[0, 1, 2, 3, 100, 1001] {
+            let mut items = random(length, 50);
+            let mut expected = items.clone();
+            expected.sort();
+            super::heapsort(&mut items[..], &|lhs: &u64, rhs: &u64| lhs.cmp(rhs));
+            assert_eq!(items, expected);
+        }
+    }
+
+    #[test]
+    fn choose_pivot_huge() {
+        // Slices of zero-sized types may be as long as `usize::MAX`:
+        let ptr = std::ptr::NonNull::<()>::dangling().as_ptr();
+        let items = unsafe { std::slice::from_raw_parts_mut(ptr, usize::MAX) };
+        choose_pivot(items, &|lhs: &(), rhs: &()| lhs.cmp(rhs));
+    }
+}
@@ -1,13 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(test), feature(lang_items))]
+#![cfg_attr(not(test), allow(internal_features, unused_features))]
 
 #[cfg(not(any(feature = "std", test)))]
 extern crate core as std;
 
-#[cfg(not(any(feature = "std", test)))]
-#[macro_use]
-extern crate std;
-
 /// Iterators for enumerating a sorting network's structure.
 pub mod generate {
     include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generate.rs"));
@@ -16,6 +13,39 @@ pub mod generate {
 #[cfg(any(feature = "std", test))]
 mod debug;
 
+pub mod columns;
+pub mod const_sort;
+pub mod exchange;
+pub mod float;
+pub mod hybrid;
+pub mod median;
+pub mod merge;
+pub mod oblivious;
+#[cfg(any(feature = "std", test))]
+pub mod selection;
+pub mod small;
+pub mod wires;
+
+pub use columns::Columns;
+pub use const_sort::{
+    sort_i128_array, sort_i16_array, sort_i32_array, sort_i64_array, sort_i8_array,
+    sort_isize_array, sort_u128_array, sort_u16_array, sort_u32_array, sort_u64_array,
+    sort_u8_array, sort_usize_array,
+};
+pub use exchange::CompareExchange;
+pub use float::{Float, FloatMinMax, NanError, NanPolicy};
+pub use hybrid::{network_sort, network_sort_by, SliceExt};
+pub use median::{FixedSizeMedianNetwork, Median};
+pub use merge::{
+    BitonicMergingNetwork, FixedSizeMergingNetwork, MergingNetwork, MergingNetworkTrait,
+    OddEvenMergingNetwork,
+};
+pub use oblivious::{Oblivious, ObliviousInteger};
+#[cfg(any(feature = "std", test))]
+pub use selection::SelectionNetwork;
+pub use small::{sort_small, sort_small_by, LengthError};
+pub use wires::{Gathered, Strided, Wires};
+
 // Branchless max(x, y)/min(x, y) for unsigned integers:
 //
 // let x: usize = 4;
@@ -28,30 +58,12 @@ mod debug;
 // println!("max({}, {}) = {}", x, y, max);
 
 #[inline]
-unsafe fn swap_unchecked<T, F>(slice: &mut [T], lhs: usize, rhs: usize, compare: F)
+unsafe fn swap_unchecked<W, F>(wires: &mut W, lhs: usize, rhs: usize, compare: F)
 where
-    F: Fn(&T, &T) -> Ordering,
+    W: Wires + ?Sized,
+    F: Fn(&W::Item, &W::Item) -> Ordering,
 {
-    use std::ptr;
-
-    let ptr = slice.as_mut_ptr();
-
-    let lhs_ptr = ptr.offset(lhs as isize);
-    let rhs_ptr = ptr.offset(rhs as isize);
-
-    let lhs_ref = &*lhs_ptr;
-    let rhs_ref = &*rhs_ptr;
-
-    let is_ordered = compare(&lhs_ref, &rhs_ref) == Ordering::Less;
-
-    let min_ptr = (if is_ordered { lhs_ref } else { rhs_ref }) as *const T;
-    let max_ptr = (if is_ordered { rhs_ref } else { lhs_ref }) as *const T;
-
-    let min_val = ptr::read(min_ptr);
-    let max_val = ptr::read(max_ptr);
-
-    ptr::write(lhs_ptr, min_val);
-    ptr::write(rhs_ptr, max_val);
+    wires.exchange_unchecked(lhs, rhs, compare)
 }
 
 /// Trait for sorting networks
@@ -64,97 +76,1506 @@ pub trait SortingNetworkTrait {
         self.sort_by(slice, |lhs, rhs| lhs.cmp(rhs))
     }
 
+    /// Sorts the passed slice with a comparator function
+    ///
+    /// Should `compare` panic, or not implement a total order,
+    /// the slice is left in an unspecified order, yet still holds each
+    /// of its elements exactly once: none are dropped or duplicated.
     fn sort_by<T, F>(&self, slice: &mut [T], compare: F)
     where
         F: Fn(&T, &T) -> Ordering;
-}
 
-pub trait FixedSizeSortingNetwork {
-    fn order() -> usize;
+    /// Sorts the passed slice with a key extraction function
+    ///
+    /// The key function is called twice per comparator.
+    fn sort_by_key<T, K, F>(&self, slice: &mut [T], key: F)
+    where
+        K: Ord,
+        F: Fn(&T) -> K,
+    {
+        self.sort_by(slice, |lhs, rhs| key(lhs).cmp(&key(rhs)))
+    }
 
-    fn width() -> usize {
-        1 << Self::order()
+    /// Sorts the passed slice with a key extraction function,
+    /// calling it only once per element
+    ///
+    /// The network is run on the extracted keys,
+    /// with each exchange being applied to the elements, too.
+    ///
+    /// By default the keys are collected into a `Vec`, which allocates
+    /// and requires the `std` feature. The fixed-size networks override
+    /// this and also provide an inherent `sort_by_cached_key`, which keeps
+    /// the keys in an array on the stack and is available without `std`.
+    #[cfg(any(feature = "std", test))]
+    fn sort_by_cached_key<T, K, F>(&self, slice: &mut [T], key: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        let mut keys: Vec<K> = slice.iter().map(key).collect();
+        self.sort_pairs(&mut keys[..], slice);
     }
-}
 
-// http://www.iti.fh-flensburg.de/lang/algorithmen/sortieren/networks/oemen.htm
-pub struct SortingNetwork;
+    /// Sorts the passed slice, preserving the order of equal elements
+    #[cfg(any(feature = "std", test))]
+    fn sort_stable<T>(&self, slice: &mut [T])
+    where
+        T: Ord,
+    {
+        self.sort_stable_by(slice, |lhs, rhs| lhs.cmp(rhs))
+    }
 
-impl SortingNetwork {
-    pub fn new() -> Self {
-        SortingNetwork
+    /// Sorts the passed slice with a comparator function,
+    /// preserving the order of equal elements
+    ///
+    /// Elements are tagged with their original positions, which break ties,
+    /// and get exchanged only if they compare as `Greater`. The result is
+    /// thus identical to that of `slice::sort_by`.
+    #[cfg(any(feature = "std", test))]
+    fn sort_stable_by<T, F>(&self, slice: &mut [T], compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let mut positions: Vec<usize> = (0..slice.len()).collect();
+        sort_tagged_by(self, slice, &mut positions[..], compare);
     }
 
-    pub fn sort<T>(&self, slice: &mut [T])
+    /// Places the `n`-th smallest element at index `n`, returning it
+    ///
+    /// Prunes the network on every call, see `SelectionNetwork::nth`
+    /// for building a pruned network once and reusing it.
+    #[cfg(any(feature = "std", test))]
+    fn select_nth<'a, T>(&self, slice: &'a mut [T], n: usize) -> &'a mut T
     where
         T: Ord,
     {
-        let len = slice.len();
-        let is_power_of_two = (len & (len - 1)) == 0;
-        assert!(is_power_of_two);
-        self.sort_internal(slice, 0, len);
+        self.select_nth_by(slice, n, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Places the `n`-th smallest element with respect to
+    /// a comparator function at index `n`, returning it
+    ///
+    /// Prunes the network on every call, see `SelectionNetwork::nth`
+    /// for building a pruned network once and reusing it.
+    #[cfg(any(feature = "std", test))]
+    fn select_nth_by<'a, T, F>(&self, slice: &'a mut [T], n: usize, compare: F) -> &'a mut T
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        SelectionNetwork::nth(self, slice.len(), n).sort_by(slice, compare);
+        &mut slice[n]
     }
 
-    fn sort_internal<T>(&self, slice: &mut [T], i: usize, n: usize)
+    /// Places the `k` smallest elements, in order, at the front of the slice
+    ///
+    /// Prunes the network on every call, see `SelectionNetwork::smallest`
+    /// for building a pruned network once and reusing it.
+    #[cfg(any(feature = "std", test))]
+    fn smallest_k<T>(&self, slice: &mut [T], k: usize)
     where
         T: Ord,
     {
-        if n <= 1 {
-            return;
-        }
-        let m = n / 2;
-        self.sort_internal(slice, i, m);
-        self.sort_internal(slice, i + m, m);
-        self.merge(slice, i, n, 1);
+        self.smallest_k_by(slice, k, |lhs, rhs| lhs.cmp(rhs))
     }
 
-    fn merge<T>(&self, slice: &mut [T], i: usize, n: usize, interval: usize)
+    /// Places the `k` smallest elements with respect to
+    /// a comparator function, in order, at the front of the slice
+    ///
+    /// Prunes the network on every call, see `SelectionNetwork::smallest`
+    /// for building a pruned network once and reusing it.
+    #[cfg(any(feature = "std", test))]
+    fn smallest_k_by<T, F>(&self, slice: &mut [T], k: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        SelectionNetwork::smallest(self, slice.len(), k).sort_by(slice, compare)
+    }
+
+    /// Places the `k` largest elements, in order, at the back of the slice
+    ///
+    /// Prunes the network on every call, see `SelectionNetwork::largest`
+    /// for building a pruned network once and reusing it.
+    #[cfg(any(feature = "std", test))]
+    fn largest_k<T>(&self, slice: &mut [T], k: usize)
     where
         T: Ord,
     {
-        let m = interval * 2;
-        if m >= n {
-            let i = i;
-            let j = i + interval;
-            if slice[i] > slice[j] {
-                slice.swap(i, j);
-                // println!("{:?}, {:?}", i, j);
+        self.largest_k_by(slice, k, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Places the `k` largest elements with respect to
+    /// a comparator function, in order, at the back of the slice
+    ///
+    /// Prunes the network on every call, see `SelectionNetwork::largest`
+    /// for building a pruned network once and reusing it.
+    #[cfg(any(feature = "std", test))]
+    fn largest_k_by<T, F>(&self, slice: &mut [T], k: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        SelectionNetwork::largest(self, slice.len(), k).sort_by(slice, compare)
+    }
+
+    /// Sorts the elements on the passed wires,
+    /// which need not be contiguous in memory (see `Wires`)
+    fn sort_wires<W>(&self, wires: &mut W)
+    where
+        W: Wires + ?Sized,
+        W::Item: Ord,
+    {
+        self.sort_wires_by(wires, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Sorts the elements on the passed wires with a comparator function,
+    /// which need not be contiguous in memory (see `Wires`)
+    fn sort_wires_by<W, F>(&self, wires: &mut W, compare: F)
+    where
+        W: Wires + ?Sized,
+        F: Fn(&W::Item, &W::Item) -> Ordering,
+    {
+        self.for_each_pair(wires.len(), |min, max| {
+            if compare(wires.wire(min), wires.wire(max)) == Ordering::Greater {
+                wires.swap_wires(min, max);
+            }
+        });
+    }
+
+    /// Sorts the passed keys, applying the same exchanges to `values`
+    fn sort_pairs<K, V>(&self, keys: &mut [K], values: &mut [V])
+    where
+        K: Ord,
+    {
+        self.sort_columns_by(keys, values, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Sorts the passed keys with a comparator function,
+    /// applying the same exchanges to `values`
+    fn sort_pairs_by<K, V, F>(&self, keys: &mut [K], values: &mut [V], compare: F)
+    where
+        F: Fn(&K, &K) -> Ordering,
+    {
+        self.sort_columns_by(keys, values, compare)
+    }
+
+    /// Sorts the passed keys, applying the same exchanges to each of `columns`
+    fn sort_columns<K, C>(&self, keys: &mut [K], columns: C)
+    where
+        K: Ord,
+        C: Columns,
+    {
+        self.sort_columns_by(keys, columns, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Sorts the passed keys with a comparator function,
+    /// applying the same exchanges to each of `columns`
+    fn sort_columns_by<K, C, F>(&self, keys: &mut [K], mut columns: C, compare: F)
+    where
+        C: Columns,
+        F: Fn(&K, &K) -> Ordering,
+    {
+        let len = keys.len();
+        assert!(columns.has_rows(len), "Expected columns of length {}", len);
+        self.for_each_pair(len, |min, max| {
+            if compare(&keys[min], &keys[max]) == Ordering::Greater {
+                keys.swap(min, max);
+                columns.swap_rows(min, max);
             }
-            return;
+        });
+    }
+
+    /// Fills `indices` with the permutation that sorts the passed slice,
+    /// leaving the slice itself untouched
+    fn argsort_into<T>(&self, slice: &[T], indices: &mut [usize])
+    where
+        T: Ord,
+    {
+        self.argsort_by_into(slice, indices, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Fills `indices` with the permutation that sorts the passed slice
+    /// with a comparator function, leaving the slice itself untouched
+    ///
+    /// Afterwards `slice[indices[0]]`, `slice[indices[1]]`, … is sorted.
+    fn argsort_by_into<T, F>(&self, slice: &[T], indices: &mut [usize], compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let len = slice.len();
+        assert!(indices.len() == len, "Expected indices of length {}", len);
+        for (index, item) in indices.iter_mut().enumerate() {
+            *item = index;
         }
-        self.merge(slice, i, n, m);
-        self.merge(slice, i + interval, n, m);
-        let mut i = i + interval;
-        while i + interval < n {
-            let j = i + interval;
-            if slice[i] > slice[j] {
-                slice.swap(i, j);
-                // println!("{:?}, {:?}", i, j);
+        self.sort_by(indices, |&lhs, &rhs| compare(&slice[lhs], &slice[rhs]))
+    }
+
+    /// Sorts the passed slice with a compare-exchange primitive
+    fn sort_with<T, C>(&self, slice: &mut [T], mut exchange: C)
+    where
+        C: CompareExchange<T>,
+    {
+        self.for_each_pair(slice.len(), |min, max| {
+            exchange.compare_exchange(slice, min, max)
+        });
+    }
+
+    /// Sorts the passed floats by their total order (see `f64::total_cmp`)
+    ///
+    /// Negative NaNs end up in front, positive NaNs in the back,
+    /// and `-0.0` is ordered before `+0.0`.
+    fn sort_floats_total<T>(&self, slice: &mut [T])
+    where
+        T: Float,
+    {
+        self.sort_by(slice, T::total_cmp)
+    }
+
+    /// Sorts the passed floats, handling NaNs as per `policy`
+    ///
+    /// Numbers are ordered by `total_cmp` (placing `-0.0` before `+0.0`),
+    /// as are NaNs among themselves with `NanPolicy::First` and `NanPolicy::Last`.
+    /// With `NanPolicy::Error` slices containing NaNs are left untouched,
+    /// while all others get sorted in the same order through the branchless `FloatMinMax`.
+    fn sort_floats<T>(&self, slice: &mut [T], policy: NanPolicy) -> Result<(), NanError>
+    where
+        T: Float,
+    {
+        match policy {
+            NanPolicy::First | NanPolicy::Last => self.sort_by(slice, float::nan_cmp(policy)),
+            NanPolicy::Error => {
+                float::find_nan(slice)?;
+                self.sort_with(slice, FloatMinMax::new());
             }
-            i += m;
         }
+        Ok(())
+    }
+
+    /// Sorts the passed integers in constant time,
+    /// i.e. without data-dependent branches or memory accesses
+    ///
+    /// See the `oblivious` module for what is (and isn't) guaranteed.
+    fn sort_oblivious<T>(&self, slice: &mut [T])
+    where
+        T: ObliviousInteger,
+    {
+        self.sort_with(slice, Oblivious::new())
+    }
+
+    /// Calls `visit` with the `(min, max)` wire indices
+    /// of each of the network's comparators for slices of length `len`, in order
+    ///
+    /// Most other methods are built on this one, which is why every network
+    /// has to provide it: the comparators can't be recovered from `sort_by`
+    /// unless it is known to compare and exchange data-obliviously.
+    fn for_each_pair<F>(&self, len: usize, visit: F)
+    where
+        F: FnMut(usize, usize);
+}
+
+/// Sorts `slice` by `compare`, breaking ties by the elements' `positions`.
+fn sort_tagged_by<N, T, F>(network: &N, slice: &mut [T], positions: &mut [usize], compare: F)
+where
+    N: SortingNetworkTrait + ?Sized,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    assert!(positions.len() == len, "Expected positions of length {}", len);
+    network.for_each_pair(len, |min, max| {
+        let ordering = compare(&slice[min], &slice[max]);
+        if ordering.then(positions[min].cmp(&positions[max])) == Ordering::Greater {
+            slice.swap(min, max);
+            positions.swap(min, max);
+        }
+    });
+}
+
+/// Trait for sorting networks of a fixed length
+pub trait FixedSizeSortingNetwork {
+    /// The length of slices the network sorts
+    const WIDTH: usize;
+
+    /// The number of comparators of the network
+    const COMPARATORS: usize;
+
+    /// The number of parallel stages of the network
+    const DEPTH: usize;
+
+    /// The network's comparators as `(min, max)` pairs of wire indices
+    ///
+    /// Unlike `pairs()` this is usable from `const fn`s.
+    const PAIRS: &'static [(u8, u8)];
+
+    /// The order of the underlying power-of-two network,
+    /// i.e. the binary logarithm of the smallest power of two not less than `WIDTH`
+    fn order() -> usize {
+        Self::WIDTH.next_power_of_two().trailing_zeros() as usize
+    }
+
+    /// The length of slices the network sorts
+    fn width() -> usize {
+        Self::WIDTH
+    }
+
+    /// The network's comparators as `(min, max)` pairs of wire indices
+    fn pairs() -> &'static [(u8, u8)] {
+        Self::PAIRS
     }
 }
 
-#[derive(Clone, Copy)]
-struct SortingNetwork1;
+/// Implements the inherent methods, `SortingNetworkTrait` and `Debug`
+/// for a family of fixed-size networks, whose comparators are
+/// provided through `FixedSizeSortingNetwork` by generated code.
+///
+/// Networks are drawn comparator by comparator, unless a formatting function is passed.
+macro_rules! impl_fixed_size_sorting_network {
+    ($name:ident) => {
+        impl_fixed_size_sorting_network!($name, |f| debug::debug_fmt_pairs(Self::pairs(), N, f));
+    };
+    ($name:ident, $debug_fmt:expr) => {
+        impl<const N: usize> $name<N> {
+            /// Creates a sorting network for slices of length `N`.
+            #[inline]
+            pub fn new() -> Self {
+                $name
+            }
+
+            /// Sorts the passed array
+            #[inline]
+            pub fn sort_array<T>(&self, array: &mut [T; N])
+            where
+                T: Ord,
+                Self: FixedSizeSortingNetwork,
+            {
+                self.sort(&mut array[..])
+            }
+
+            /// Sorts the passed array with a comparator function
+            #[inline]
+            pub fn sort_by_array<T, F>(&self, array: &mut [T; N], compare: F)
+            where
+                F: Fn(&T, &T) -> Ordering,
+                Self: FixedSizeSortingNetwork,
+            {
+                self.sort_by(&mut array[..], compare)
+            }
+
+            /// Sorts the passed slice with a key extraction function,
+            /// calling it only once per element
+            ///
+            /// Unlike `SortingNetworkTrait::sort_by_cached_key` the keys
+            /// are kept in an array, so this doesn't need the `std` feature.
+            pub fn sort_by_cached_key<T, K, F>(&self, slice: &mut [T], mut key: F)
+            where
+                K: Ord,
+                F: FnMut(&T) -> K,
+                Self: FixedSizeSortingNetwork,
+            {
+                let len = slice.len();
+                assert!(len == N, "Expected slice of length {}", N);
+                let mut keys: [K; N] = std::array::from_fn(|index| key(&slice[index]));
+                self.sort_pairs(&mut keys[..], slice);
+            }
+
+            /// Sorts the passed slice, preserving the order of equal elements
+            ///
+            /// Unlike `SortingNetworkTrait::sort_stable` the original positions
+            /// are kept in an array, so this doesn't need the `std` feature.
+            #[inline]
+            pub fn sort_stable<T>(&self, slice: &mut [T])
+            where
+                T: Ord,
+                Self: FixedSizeSortingNetwork,
+            {
+                $name::sort_stable_by(self, slice, |lhs, rhs| lhs.cmp(rhs))
+            }
+
+            /// Sorts the passed slice with a comparator function,
+            /// preserving the order of equal elements
+            ///
+            /// Unlike `SortingNetworkTrait::sort_stable_by` the original positions
+            /// are kept in an array, so this doesn't need the `std` feature.
+            pub fn sort_stable_by<T, F>(&self, slice: &mut [T], compare: F)
+            where
+                F: Fn(&T, &T) -> Ordering,
+                Self: FixedSizeSortingNetwork,
+            {
+                let mut positions: [usize; N] = std::array::from_fn(|index| index);
+                sort_tagged_by(self, slice, &mut positions[..], compare);
+            }
+
+            /// Returns the permutation that sorts the passed array,
+            /// leaving the array itself untouched
+            #[inline]
+            pub fn argsort<T>(&self, array: &[T; N]) -> [usize; N]
+            where
+                T: Ord,
+                Self: FixedSizeSortingNetwork,
+            {
+                self.argsort_by(array, |lhs, rhs| lhs.cmp(rhs))
+            }
+
+            /// Returns the permutation that sorts the passed array
+            /// with a comparator function, leaving the array itself untouched
+            ///
+            /// Afterwards `array[indices[0]]`, `array[indices[1]]`, … is sorted.
+            #[inline]
+            pub fn argsort_by<T, F>(&self, array: &[T; N], compare: F) -> [usize; N]
+            where
+                F: Fn(&T, &T) -> Ordering,
+                Self: FixedSizeSortingNetwork,
+            {
+                let mut indices: [usize; N] = std::array::from_fn(|index| index);
+                self.sort_by(&mut indices[..], |&lhs, &rhs| compare(&array[lhs], &array[rhs]));
+                indices
+            }
+
+            /// Sorts every `N`-element chunk of the passed slice,
+            /// returning the trailing elements that don't fill a chunk
+            #[inline]
+            pub fn sort_chunks_exact<'a, T>(&self, slice: &'a mut [T]) -> &'a mut [T]
+            where
+                T: Ord,
+                Self: FixedSizeSortingNetwork,
+            {
+                self.sort_chunks_exact_by(slice, |lhs, rhs| lhs.cmp(rhs))
+            }
+
+            /// Sorts every `N`-element chunk of the passed slice with a comparator function,
+            /// returning the trailing elements that don't fill a chunk
+            ///
+            /// The remainder is left untouched, just like `slice::chunks_exact_mut`'s.
+            pub fn sort_chunks_exact_by<'a, T, F>(&self, slice: &'a mut [T], compare: F) -> &'a mut [T]
+            where
+                F: Fn(&T, &T) -> Ordering,
+                Self: FixedSizeSortingNetwork,
+            {
+                let mut chunks = slice.chunks_exact_mut(N);
+                for chunk in &mut chunks {
+                    self.sort_by(chunk, &compare);
+                }
+                chunks.into_remainder()
+            }
+
+            /// Sorts every `N`-element chunk of the passed slice,
+            /// interleaving the work on multiple chunks at once
+            ///
+            /// Exchanges elements through `Ord::min` and `Ord::max`,
+            /// see `sort_chunks_interleaved_by` for details.
+            #[inline]
+            pub fn sort_chunks_interleaved<'a, T>(&self, slice: &'a mut [T]) -> &'a mut [T]
+            where
+                T: Ord + Copy,
+                Self: FixedSizeSortingNetwork,
+            {
+                let remainder = self.sort_chunks_interleaved_with(slice, |min, max| {
+                    let (lhs, rhs) = (*min, *max);
+                    *min = lhs.min(rhs);
+                    *max = lhs.max(rhs);
+                });
+                self.sort_chunks_exact(remainder)
+            }
+
+            /// Sorts every `N`-element chunk of the passed slice with a comparator function,
+            /// interleaving the work on multiple chunks at once
+            ///
+            /// Chunks get transposed in groups of 16 into a struct-of-arrays buffer,
+            /// on which every comparator is applied to all 16 chunks in a branchless loop,
+            /// allowing the compiler to vectorize it for cheap comparisons.
+            /// This pays off once the target has vector min/max instructions
+            /// for `T` (e.g. SSE4.1 for `u32`, enabled via `-C target-cpu`).
+            /// Chunks that don't fill a group are sorted one by one.
+            ///
+            /// Returns the trailing elements that don't fill a chunk, leaving them untouched.
+            pub fn sort_chunks_interleaved_by<'a, T, F>(&self, slice: &'a mut [T], compare: F) -> &'a mut [T]
+            where
+                T: Copy,
+                F: Fn(&T, &T) -> Ordering,
+                Self: FixedSizeSortingNetwork,
+            {
+                let remainder = self.sort_chunks_interleaved_with(slice, |min, max| {
+                    let (lhs, rhs) = (*min, *max);
+                    let swap = compare(&lhs, &rhs) == Ordering::Greater;
+                    *min = if swap { rhs } else { lhs };
+                    *max = if swap { lhs } else { rhs };
+                });
+                self.sort_chunks_exact_by(remainder, compare)
+            }
+
+            /// Runs the network on groups of 16 transposed chunks, returning
+            /// the trailing chunks (and elements) that don't fill a group.
+            fn sort_chunks_interleaved_with<'a, T, E>(&self, slice: &'a mut [T], exchange: E) -> &'a mut [T]
+            where
+                T: Copy,
+                E: Fn(&mut T, &mut T),
+                Self: FixedSizeSortingNetwork,
+            {
+                const LANES: usize = 16;
+
+                let mut groups = slice.chunks_exact_mut(N * LANES);
+                for group in &mut groups {
+                    let mut wires: [[T; LANES]; N] =
+                        std::array::from_fn(|wire| std::array::from_fn(|lane| group[lane * N + wire]));
+                    for &(min, max) in Self::pairs() {
+                        let (head, tail) = wires.split_at_mut(max as usize);
+                        let (min, max) = (&mut head[min as usize], &mut tail[0]);
+                        for (min, max) in min.iter_mut().zip(max.iter_mut()) {
+                            exchange(min, max);
+                        }
+                    }
+                    for (wire, lanes) in wires.iter().enumerate() {
+                        for (lane, &item) in lanes.iter().enumerate() {
+                            group[lane * N + wire] = item;
+                        }
+                    }
+                }
+                groups.into_remainder()
+            }
+        }
+
+        impl<const N: usize> SortingNetworkTrait for $name<N>
+        where
+            Self: FixedSizeSortingNetwork,
+        {
+            fn sort_by<T, F>(&self, slice: &mut [T], compare: F)
+            where
+                F: Fn(&T, &T) -> Ordering,
+            {
+                self.sort_wires_by(slice, compare)
+            }
+
+            fn sort_wires_by<W, F>(&self, wires: &mut W, compare: F)
+            where
+                W: Wires + ?Sized,
+                F: Fn(&W::Item, &W::Item) -> Ordering,
+            {
+                let len = wires.len();
+                assert!(len == N, "Expected slice of length {}", N);
+                for &(min, max) in Self::pairs() {
+                    unsafe {
+                        swap_unchecked(wires, min as usize, max as usize, &compare);
+                    }
+                }
+            }
+
+            #[cfg(any(feature = "std", test))]
+            fn sort_by_cached_key<T, K, F>(&self, slice: &mut [T], key: F)
+            where
+                K: Ord,
+                F: FnMut(&T) -> K,
+            {
+                $name::sort_by_cached_key(self, slice, key)
+            }
+
+            #[cfg(any(feature = "std", test))]
+            fn sort_stable_by<T, F>(&self, slice: &mut [T], compare: F)
+            where
+                F: Fn(&T, &T) -> Ordering,
+            {
+                $name::sort_stable_by(self, slice, compare)
+            }
+
+            fn for_each_pair<F>(&self, len: usize, mut visit: F)
+            where
+                F: FnMut(usize, usize),
+            {
+                assert!(len == N, "Expected slice of length {}", N);
+                for &(min, max) in Self::pairs() {
+                    visit(min as usize, max as usize);
+                }
+            }
+        }
+
+        #[cfg(any(feature = "std", test))]
+        impl<const N: usize> std::fmt::Debug for $name<N>
+        where
+            Self: FixedSizeSortingNetwork,
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                ($debug_fmt)(f)
+            }
+        }
+    };
+}
+
+/// Optimized sorting network for slices of length `N`.
+///
+/// Networks are generated for every `N` from `2` up to `64`
+/// (or up to `256` with the `wide-networks` feature)
+/// and are also available through aliases, such as `SortingNetwork16`.
+///
+/// Up to version 0.1 `SortingNetwork` sorted slices of any length,
+/// which is now done by `RuntimeSortingNetwork`.
+#[derive(Clone, Copy, Default)]
+pub struct SortingNetwork<const N: usize>;
+
+impl_fixed_size_sorting_network!(SortingNetwork, |f| debug::debug_fmt(Self::order(), N, f));
+
+/// Bitonic sorting network for slices of length `N`.
+///
+/// Networks are generated for every power of two `N` from `2` up to `64`
+/// (or up to `256` with the `wide-networks` feature)
+/// and are also available through aliases, such as `BitonicSortingNetwork16`.
+/// They need more comparators than `SortingNetwork<N>`, but every one of
+/// their stages consists of `N / 2` comparators, which maps well to SIMD.
+#[derive(Clone, Copy, Default)]
+pub struct BitonicSortingNetwork<const N: usize>;
+
+impl_fixed_size_sorting_network!(BitonicSortingNetwork);
+
+/// Parberry's pairwise sorting network for slices of length `N`.
+///
+/// Networks are generated for every power of two `N` from `2` up to `64`
+/// (or up to `256` with the `wide-networks` feature)
+/// and are also available through aliases, such as `PairwiseSortingNetwork16`.
+/// They have the same size and depth as `SortingNetwork<N>`, but are wired differently.
+#[derive(Clone, Copy, Default)]
+pub struct PairwiseSortingNetwork<const N: usize>;
+
+impl_fixed_size_sorting_network!(PairwiseSortingNetwork);
+
+/// Bose and Nelson's sorting network for slices of length `N`.
+///
+/// Networks are generated for every `N` from `2` up to `32`
+/// and are also available through aliases, such as `BoseNelsonSortingNetwork13`.
+/// They are constructed natively for every `N`, rather than being pruned from
+/// the next larger power of two. Compared with `SortingNetwork<N>`, they need fewer
+/// comparators for `N = 9` (27 vs. 28) and `N = 17` (81 vs. 85), as many for the other `N`
+/// up to 12 and for `N = 18`, but more for all other `N`, e.g. 50 vs. 48 for `N = 13`
+/// and 211 vs. 191 for `N = 32`.
+#[derive(Clone, Copy, Default)]
+pub struct BoseNelsonSortingNetwork<const N: usize>;
+
+impl_fixed_size_sorting_network!(BoseNelsonSortingNetwork);
+
+/// Odd-even transposition ("brick") sorting network for slices of length `N`.
+///
+/// Networks are generated for every `N` from `2` up to `32`
+/// and are also available through aliases, such as `OddEvenTranspositionSortingNetwork8`.
+/// Only ever comparing adjacent wires, they are the simplest sorting networks,
+/// but need `N * (N - 1) / 2` comparators in `N` layers, which makes them a baseline for the others.
+#[derive(Clone, Copy, Default)]
+pub struct OddEvenTranspositionSortingNetwork<const N: usize>;
+
+impl_fixed_size_sorting_network!(OddEvenTranspositionSortingNetwork);
+
+/// Best-known sorting network with the fewest comparators for slices of length `N`.
+///
+/// Networks are generated for every `N` from `2` up to `16` from a curated table (`optimal.rs`)
+/// and are also available through aliases, such as `OptimalSortingNetwork16`.
+/// The published best-known networks for larger `N` are not part of the table.
+/// They are the smallest networks known, such as Green's network
+/// with 60 comparators for `N = 16` (versus 63 for `SortingNetwork16`).
+#[derive(Clone, Copy, Default)]
+pub struct OptimalSortingNetwork<const N: usize>;
+
+impl_fixed_size_sorting_network!(OptimalSortingNetwork);
 
-impl SortingNetwork1 {
-    #[inline]
+/// Best-known sorting network with the fewest layers for slices of length `N`.
+///
+/// Networks are generated for every `N` from `2` up to `16` from a curated table (`optimal.rs`)
+/// and are also available through aliases, such as `DepthOptimalSortingNetwork16`.
+/// The published best-known networks for larger `N` are not part of the table.
+/// Their depth is optimal, such as 9 for `N = 16` (versus 10 for `SortingNetwork16`),
+/// at the cost of a few more comparators than `OptimalSortingNetwork<N>` for some `N`.
+#[derive(Clone, Copy, Default)]
+pub struct DepthOptimalSortingNetwork<const N: usize>;
+
+impl_fixed_size_sorting_network!(DepthOptimalSortingNetwork);
+
+/// Sorting network for slices of arbitrary length.
+///
+/// Comparators are generated by Batcher's merge-exchange sort (see `generate::MergeExchange`),
+/// which handles lengths that aren't powers of two natively.
+#[derive(Clone, Copy, Default)]
+pub struct RuntimeSortingNetwork;
+
+impl RuntimeSortingNetwork {
+    /// Creates a sorting network for slices of arbitrary length.
     pub fn new() -> Self {
-        SortingNetwork1
+        RuntimeSortingNetwork
+    }
+
+    /// Returns an iterator over the network's comparators for slices of length `len`
+    pub fn pairs(&self, len: usize) -> generate::MergeExchange {
+        generate::MergeExchange::new(len)
     }
-    // pub fn width() -> usize { 1 }
 }
 
-impl SortingNetworkTrait for SortingNetwork1 {
-    #[inline]
-    fn sort_by<T, F>(&self, _slice: &mut [T], _compare: F)
+impl SortingNetworkTrait for RuntimeSortingNetwork {
+    fn sort_by<T, F>(&self, slice: &mut [T], compare: F)
     where
         F: Fn(&T, &T) -> Ordering,
     {
-        // intentionally left blank
+        self.sort_wires_by(slice, compare)
+    }
+
+    fn sort_wires_by<W, F>(&self, wires: &mut W, compare: F)
+    where
+        W: Wires + ?Sized,
+        F: Fn(&W::Item, &W::Item) -> Ordering,
+    {
+        let len = wires.len();
+        self.for_each_pair(len, |min, max| unsafe {
+            swap_unchecked(wires, min, max, &compare);
+        });
+    }
+
+    fn for_each_pair<F>(&self, len: usize, mut visit: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        for pair in self.pairs(len) {
+            visit(pair.min, pair.max);
+        }
     }
 }
 
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::iter::FromIterator;
+    use std::ops::Range;
+    use std::panic;
+    use std::prelude::v1::*;
+    use std::rc::Rc;
+    use super::*;
+
+    pub(crate) fn shuffled(length: usize) -> Vec<usize> {
+        let prime = 313373;
+        let sorted: Vec<_> = (0..length).collect();
+        (0..length).map(|i| sorted[(i * prime) % length]).collect()
+    }
+
+    /// Calls `check` with the network of `family` for each of the listed widths.
+    macro_rules! check_widths {
+        ($check:ident, $family:ident: [$($width:expr),*]) => {
+            $($check($family::<$width>::new());)*
+        };
+    }
+
+    /// Checks that `pairs` places the correct element at each of `outputs`
+    /// for all `2^width` inputs of zeros and ones, which by the
+    /// zero-one principle holds for arbitrary inputs as well.
+    pub(crate) fn is_selecting(width: usize, pairs: &[(u8, u8)], outputs: Range<usize>) -> bool {
+        // Each word holds a batch of 64 inputs, one per bit,
+        // with the lower six wires enumerating all combinations:
+        const LOWER: [u64; 6] = [
+            0xAAAA_AAAA_AAAA_AAAA,
+            0xCCCC_CCCC_CCCC_CCCC,
+            0xF0F0_F0F0_F0F0_F0F0,
+            0xFF00_FF00_FF00_FF00,
+            0xFFFF_0000_FFFF_0000,
+            0xFFFF_FFFF_0000_0000,
+        ];
+        let lower = width.min(6);
+        let mask = if lower == 6 { !0 } else { (1u64 << (1 << lower)) - 1 };
+        let mut wires = vec![0u64; width];
+        for batch in 0..(1u64 << (width - lower)) {
+            for (index, wire) in wires.iter_mut().enumerate() {
+                *wire = if index < lower {
+                    LOWER[index] & mask
+                } else if (batch >> (index - lower)) & 1 == 1 {
+                    mask
+                } else {
+                    0
+                };
+            }
+            for &(min, max) in pairs {
+                let (lhs, rhs) = (wires[min as usize], wires[max as usize]);
+                wires[min as usize] = lhs & rhs;
+                wires[max as usize] = lhs | rhs;
+            }
+            let upper_ones = batch.count_ones() as usize;
+            for bit in 0..(1 << lower) {
+                let zeros = width - upper_ones - (bit as u64).count_ones() as usize;
+                for output in outputs.clone() {
+                    let expected = (output >= zeros) as u64;
+                    if (wires[output] >> bit) & 1 != expected {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn runtime_sort() {
+        for length in 0..130 {
+            let mut items = shuffled(length);
+            RuntimeSortingNetwork::new().sort(&mut items[..]);
+            let expected: Vec<_> = (0..length).collect();
+            assert_eq!(items, expected);
+        }
+    }
+
+    #[test]
+    fn merge_exchange() {
+        let network = RuntimeSortingNetwork::new();
+        for len in 0..17 {
+            let pairs: Vec<(u8, u8)> = network
+                .pairs(len)
+                .map(|pair| (pair.min as u8, pair.max as u8))
+                .collect();
+            assert!(pairs.iter().all(|&(min, max)| min < max && (max as usize) < len));
+            assert!(len < 2 || is_selecting(len, &pairs, 0..len));
+        }
+        assert_eq!(network.pairs(16).count(), SortingNetwork16::COMPARATORS);
+        // Lengths that aren't powers of two need fewer comparators than pruned networks:
+        assert_eq!(network.pairs(17).count(), 74);
+        assert_eq!(SortingNetwork17::COMPARATORS, 85);
+    }
+
+    #[test]
+    fn runtime_sort_by() {
+        for length in 0..130 {
+            let mut items = shuffled(length);
+            RuntimeSortingNetwork::new().sort_by(&mut items[..], |lhs, rhs| rhs.cmp(lhs));
+            let expected: Vec<_> = (0..length).rev().collect();
+            assert_eq!(items, expected);
+        }
+    }
+
+    #[test]
+    fn fixed_size_array() {
+        let mut items = [7, 6, 5, 4, 3, 2, 1];
+        SortingNetwork::<7>::new().sort_array(&mut items);
+        assert_eq!(items, [1, 2, 3, 4, 5, 6, 7]);
+
+        let mut items = [1, 2, 3, 4, 5];
+        SortingNetwork5::new().sort_by_array(&mut items, |lhs, rhs| rhs.cmp(lhs));
+        assert_eq!(items, [5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn fixed_size_consts() {
+        let buffer = [0u8; SortingNetwork16::COMPARATORS];
+        assert_eq!(buffer.len(), SortingNetwork16::pairs().len());
+        assert_eq!(SortingNetwork::<16>::WIDTH, 16);
+        assert_eq!(SortingNetwork::<16>::COMPARATORS, 63);
+        assert_eq!(SortingNetwork::<16>::DEPTH, 10);
+        assert_eq!(SortingNetwork::<9>::COMPARATORS, 28);
+    }
+
+    #[test]
+    fn sort_chunks() {
+        let network = SortingNetwork::<8>::new();
+        for &len in &[0, 7, 8, 9, 8 * 16, 8 * 16 + 3, 8 * 40 + 5] {
+            let items: Vec<usize> = shuffled(len).into_iter().map(|item| item % 5).collect();
+            let mut expected = items.clone();
+            for chunk in expected.chunks_mut(8) {
+                if chunk.len() == 8 {
+                    chunk.sort();
+                }
+            }
+
+            let mut exact = items.clone();
+            assert_eq!(network.sort_chunks_exact(&mut exact[..]).len(), len % 8);
+            assert_eq!(exact, expected);
+
+            let mut interleaved = items.clone();
+            assert_eq!(network.sort_chunks_interleaved(&mut interleaved[..]).len(), len % 8);
+            assert_eq!(interleaved, expected);
+        }
+
+        // A full group of 16 chunks, three leftover chunks and five leftover elements:
+        let len = 8 * (16 + 3) + 5;
+        let items: Vec<usize> = shuffled(len);
+        let mut expected = items.clone();
+        for chunk in expected.chunks_exact_mut(8) {
+            chunk.sort_by(|lhs, rhs| rhs.cmp(lhs));
+        }
+
+        let mut exact = items.clone();
+        let remainder = network.sort_chunks_exact_by(&mut exact[..], |lhs, rhs| rhs.cmp(lhs));
+        assert_eq!(remainder, &items[(len - 5)..]);
+        assert_eq!(exact, expected);
+
+        let mut interleaved = items.clone();
+        let remainder = network.sort_chunks_interleaved_by(&mut interleaved[..], |lhs, rhs| rhs.cmp(lhs));
+        assert_eq!(remainder, &items[(len - 5)..]);
+        assert_eq!(interleaved, expected);
+    }
+
+    #[test]
+    fn sort_wires() {
+        use std::collections::VecDeque;
+
+        // A row-major 8x3 matrix, sorting its middle column:
+        let mut matrix: Vec<usize> = (0..24).collect();
+        for row in 0..8 {
+            matrix[row * 3 + 1] = 7 - row;
+        }
+        SortingNetwork8::new().sort_wires(&mut Strided::new(&mut matrix[..], 1, 3));
+        let column: Vec<_> = matrix.iter().skip(1).step_by(3).cloned().collect();
+        assert_eq!(column, (0..8).collect::<Vec<_>>());
+        let others: Vec<_> = matrix.iter().step_by(3).cloned().collect();
+        assert_eq!(others, (0..8).map(|row| row * 3).collect::<Vec<_>>());
+        assert_eq!(Strided::new(&mut matrix[..], 2, 4).len(), 6);
+        assert_eq!(Strided::new(&mut matrix[..], 24, 4).len(), 0);
+
+        let mut items = vec![9, 3, 8, 2, 7, 1, 6, 0];
+        let indices = [0, 2, 4, 6];
+        RuntimeSortingNetwork::new().sort_wires(&mut Gathered::new(&mut items[..], &indices));
+        assert_eq!(items, vec![6, 3, 7, 2, 8, 1, 9, 0]);
+
+        // Repeated indices alias, only permuting the slice:
+        let mut items = vec![9, 3, 8, 2, 7, 1, 6, 0];
+        let indices = [6, 0, 6, 2, 0];
+        RuntimeSortingNetwork::new().sort_wires(&mut Gathered::new(&mut items[..], &indices));
+        let mut sorted = items.clone();
+        sorted.sort();
+        assert_eq!(sorted, vec![0, 1, 2, 3, 6, 7, 8, 9]);
+
+        for len in 0..20 {
+            let mut deque: VecDeque<usize> = shuffled(len).into_iter().collect();
+            // Force the deque to wrap around, so that it is backed by two slices:
+            deque.rotate_left(len / 3);
+            RuntimeSortingNetwork::new().sort_wires_by(&mut deque, |lhs, rhs| rhs.cmp(lhs));
+            assert_eq!(deque, (0..len).rev().collect::<Vec<_>>());
+        }
+
+        let mut deque: VecDeque<usize> = shuffled(16).into_iter().collect();
+        deque.rotate_right(5);
+        SortingNetwork16::new().sort_wires(&mut deque);
+        assert_eq!(deque, (0..16).collect::<Vec<_>>());
+    }
+
+    /// Element type counting its drops, to detect double drops and leaks
+    struct Tracked {
+        value: Box<usize>,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    fn tracked<C>(length: usize, drops: &Rc<Cell<usize>>) -> C
+    where
+        C: FromIterator<Tracked>,
+    {
+        shuffled(length)
+            .into_iter()
+            .map(|value| Tracked { value: Box::new(value), drops: drops.clone() })
+            .collect()
+    }
+
+    fn values(items: &[Tracked]) -> Vec<usize> {
+        let mut values: Vec<usize> = items.iter().map(|item| *item.value).collect();
+        values.sort();
+        values
+    }
+
+    /// Sorts through `sort`, with a comparator that panics on its `panic_at`-th call,
+    /// and checks that the elements are neither lost nor duplicated.
+    fn check_panic_safety<S>(length: usize, panic_at: usize, sort: S)
+    where
+        S: Fn(&mut [Tracked], &dyn Fn(&Tracked, &Tracked) -> Ordering),
+    {
+        check_panic_safety_in::<Vec<Tracked>, _>(length, panic_at, |items, compare| {
+            sort(&mut items[..], compare)
+        });
+    }
+
+    /// Like `check_panic_safety`, but with the elements held by a container of type `C`.
+    fn check_panic_safety_in<C, S>(length: usize, panic_at: usize, sort: S)
+    where
+        C: FromIterator<Tracked> + IntoIterator<Item = Tracked>,
+        S: Fn(&mut C, &dyn Fn(&Tracked, &Tracked) -> Ordering),
+    {
+        let drops = Rc::new(Cell::new(0));
+        let calls = Cell::new(0);
+        let mut items: C = tracked(length, &drops);
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            sort(&mut items, &|lhs, rhs| {
+                calls.set(calls.get() + 1);
+                if calls.get() == panic_at {
+                    // Unlike `panic!` this doesn't invoke the panic hook, keeping the output clean:
+                    panic::resume_unwind(Box::new("comparator panicked"));
+                }
+                lhs.value.cmp(&rhs.value)
+            });
+        }));
+        assert_eq!(result.is_err(), (1..=calls.get()).contains(&panic_at));
+        let items: Vec<Tracked> = items.into_iter().collect();
+        assert_eq!(values(&items), (0..length).collect::<Vec<_>>());
+        assert_eq!(drops.get(), 0);
+        drop(items);
+        assert_eq!(drops.get(), length);
+    }
+
+    /// Sorts through `sort`, with a comparator returning arbitrary orderings,
+    /// and checks that the elements are neither lost nor duplicated.
+    fn check_inconsistent<S>(length: usize, sort: S)
+    where
+        S: Fn(&mut [Tracked], &dyn Fn(&Tracked, &Tracked) -> Ordering),
+    {
+        let drops = Rc::new(Cell::new(0));
+        let state = Cell::new(0x9E37_79B9_u32);
+        let mut items: Vec<Tracked> = tracked(length, &drops);
+        sort(&mut items[..], &|_, _| {
+            let mut x = state.get();
+            x ^= x << 13;
+            x ^= x >> 17;
+            x ^= x << 5;
+            state.set(x);
+            [Ordering::Less, Ordering::Equal, Ordering::Greater][x as usize % 3]
+        });
+        assert_eq!(values(&items), (0..length).collect::<Vec<_>>());
+        drop(items);
+        assert_eq!(drops.get(), length);
+    }
+
+    #[test]
+    fn panic_safety() {
+        let widths: Vec<usize> = if cfg!(miri) {
+            vec![2, 3, 8, 16, 33]
+        } else {
+            (2..=MAX_WIDTH).collect()
+        };
+        for &width in &widths {
+            let comparators = sorting_network_pairs(width).unwrap().len();
+            for &panic_at in &[1, comparators / 2, comparators, comparators + 1] {
+                check_panic_safety(width, panic_at, |items, compare| {
+                    sort_small_by(items, compare).unwrap();
+                });
+            }
+            check_inconsistent(width, |items, compare| {
+                sort_small_by(items, compare).unwrap();
+            });
+        }
+
+        for &panic_at in &[1, 30, 63] {
+            check_panic_safety(16, panic_at, |items, compare| {
+                SortingNetwork16::new().sort_by(items, compare);
+            });
+            check_panic_safety(20, panic_at, |items, compare| {
+                RuntimeSortingNetwork::new().sort_by(items, compare);
+            });
+            check_panic_safety(20, panic_at, |items, compare| {
+                RuntimeSortingNetwork::new().sort_wires_by(&mut Strided::new(items, 0, 1), compare);
+            });
+            check_panic_safety(9, panic_at, |items, compare| {
+                let network = SelectionNetwork::nth(&RuntimeSortingNetwork::new(), 9, 4);
+                network.sort_by(items, compare);
+            });
+            check_panic_safety(100, panic_at, |items, compare| {
+                network_sort_by(items, compare);
+            });
+        }
+        check_inconsistent(16, |items, compare| SortingNetwork16::new().sort_by(items, compare));
+        check_inconsistent(20, |items, compare| RuntimeSortingNetwork::new().sort_by(items, compare));
+        check_inconsistent(100, |items, compare| network_sort_by(items, compare));
+    }
+
+    /// Checks the panic safety of the network's exchanges
+    /// on slices, as well as on strided, gathered and `VecDeque` wires.
+    fn check_exchanges<S>(network: S)
+    where
+        S: FixedSizeSortingNetwork + SortingNetworkTrait,
+    {
+        use std::collections::VecDeque;
+
+        let width = S::WIDTH;
+        // Every other element, with the gathered ones in reverse:
+        let indices: Vec<usize> = (0..width).rev().map(|index| 2 * index + 1).collect();
+        for &panic_at in &[1, S::COMPARATORS / 2, S::COMPARATORS, S::COMPARATORS + 1] {
+            check_panic_safety(width, panic_at, |items, compare| {
+                network.sort_by(items, compare);
+            });
+            check_panic_safety(2 * width, panic_at, |items, compare| {
+                network.sort_wires_by(&mut Strided::new(items, 0, 2), compare);
+            });
+            check_panic_safety(2 * width, panic_at, |items, compare| {
+                network.sort_wires_by(&mut Gathered::new(items, &indices), compare);
+            });
+            check_panic_safety_in::<VecDeque<Tracked>, _>(width, panic_at, |deque, compare| {
+                // Force the deque to wrap around, so that it is backed by two slices:
+                deque.rotate_left(width / 3);
+                network.sort_wires_by(deque, compare);
+            });
+        }
+        check_inconsistent(width, |items, compare| network.sort_by(items, compare));
+        check_inconsistent(2 * width, |items, compare| {
+            network.sort_wires_by(&mut Strided::new(items, 1, 2), compare);
+        });
+        check_inconsistent(2 * width, |items, compare| {
+            network.sort_wires_by(&mut Gathered::new(items, &indices), compare);
+        });
+    }
+
+    #[test]
+    fn panic_safety_families() {
+        // Small widths of each generated family, so that this runs under Miri, too:
+        check_widths!(check_exchanges, SortingNetwork: [3, 8]);
+        check_widths!(check_exchanges, BitonicSortingNetwork: [2, 8]);
+        check_widths!(check_exchanges, PairwiseSortingNetwork: [2, 8]);
+        check_widths!(check_exchanges, BoseNelsonSortingNetwork: [3, 7]);
+        check_widths!(check_exchanges, OddEvenTranspositionSortingNetwork: [3, 6]);
+        check_widths!(check_exchanges, OptimalSortingNetwork: [5, 9]);
+        check_widths!(check_exchanges, DepthOptimalSortingNetwork: [5, 9]);
+    }
+
+    /// Returns the number of parallel stages of a network,
+    /// with each comparator placed into the earliest possible stage.
+    fn depth(width: usize, pairs: &[(u8, u8)]) -> usize {
+        let mut depths = vec![0; width];
+        for &(min, max) in pairs {
+            let depth = depths[min as usize].max(depths[max as usize]) + 1;
+            depths[min as usize] = depth;
+            depths[max as usize] = depth;
+        }
+        depths.into_iter().max().unwrap_or(0)
+    }
+
+    /// Checks that the fixed-size network sorts all inputs of zeros and ones
+    /// (for small widths), as well as some permutations (for larger widths).
+    fn check_fixed_size_network<S>(network: S)
+    where
+        S: FixedSizeSortingNetwork + SortingNetworkTrait,
+    {
+        let width = S::WIDTH;
+        assert_eq!(S::pairs().len(), S::COMPARATORS);
+        assert!(S::pairs().iter().all(|&(min, max)| min < max && (max as usize) < width));
+        if width <= 16 {
+            assert!(is_selecting(width, S::pairs(), 0..width));
+        }
+        for prime in &[313373, 7919, 104729] {
+            let mut items: Vec<usize> = (0..width).map(|index| (index * prime) % width).collect();
+            network.sort(&mut items[..]);
+            assert_eq!(items, (0..width).collect::<Vec<_>>());
+        }
+        let mut items: Vec<usize> = (0..width).rev().collect();
+        network.sort(&mut items[..]);
+        assert_eq!(items, (0..width).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn bitonic_sorting_networks() {
+        check_widths!(check_fixed_size_network, BitonicSortingNetwork: [2, 4, 8, 16, 32, 64]);
+        #[cfg(feature = "wide-networks")]
+        check_widths!(check_fixed_size_network, BitonicSortingNetwork: [128, 256]);
+
+        // `N / 4 * k * (k + 1)` comparators in `k * (k + 1) / 2` stages for `N = 2^k`:
+        assert_eq!(BitonicSortingNetwork16::COMPARATORS, 80);
+        assert_eq!(BitonicSortingNetwork16::DEPTH, 10);
+        assert_eq!(BitonicSortingNetwork64::COMPARATORS, 672);
+        assert_eq!(BitonicSortingNetwork64::DEPTH, 21);
+        #[cfg(feature = "wide-networks")]
+        {
+            assert_eq!(BitonicSortingNetwork256::COMPARATORS, 4608);
+            assert_eq!(BitonicSortingNetwork256::DEPTH, 36);
+        }
+
+        let debug = format!("{:?}", BitonicSortingNetwork4::new());
+        assert_eq!(
+            debug,
+            "  0: ─┰─┰───┰─\n  1: ─┸─╂─┰─┸─\n  2: ─┰─╂─┸─┰─\n  3: ─┸─┸───┸─\n"
+        );
+    }
+
+    #[test]
+    fn pairwise_sorting_networks() {
+        check_widths!(check_fixed_size_network, PairwiseSortingNetwork: [2, 4, 8, 16, 32, 64]);
+        #[cfg(feature = "wide-networks")]
+        check_widths!(check_fixed_size_network, PairwiseSortingNetwork: [128, 256]);
+
+        // Same size and depth as odd-even merge sort:
+        assert_eq!(PairwiseSortingNetwork16::COMPARATORS, SortingNetwork16::COMPARATORS);
+        assert_eq!(PairwiseSortingNetwork16::DEPTH, SortingNetwork16::DEPTH);
+        assert_eq!(PairwiseSortingNetwork64::COMPARATORS, SortingNetwork64::COMPARATORS);
+        assert_eq!(PairwiseSortingNetwork64::DEPTH, SortingNetwork64::DEPTH);
+        #[cfg(feature = "wide-networks")]
+        {
+            assert_eq!(PairwiseSortingNetwork256::COMPARATORS, SortingNetwork256::COMPARATORS);
+            assert_eq!(PairwiseSortingNetwork256::DEPTH, SortingNetwork256::DEPTH);
+        }
+        assert_ne!(PairwiseSortingNetwork16::pairs(), SortingNetwork16::pairs());
+    }
+
+    #[test]
+    fn bose_nelson_sorting_networks() {
+        check_widths!(check_fixed_size_network, BoseNelsonSortingNetwork: [
+            2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+        ]);
+
+        assert_eq!(BoseNelsonSortingNetwork5::COMPARATORS, 9);
+        assert_eq!(BoseNelsonSortingNetwork7::COMPARATORS, 16);
+        assert_eq!(BoseNelsonSortingNetwork9::COMPARATORS, 27);
+        assert_eq!(SortingNetwork9::COMPARATORS, 28);
+        assert_eq!(BoseNelsonSortingNetwork17::COMPARATORS, 81);
+        assert_eq!(SortingNetwork17::COMPARATORS, 85);
+        assert_eq!(BoseNelsonSortingNetwork13::COMPARATORS, 50);
+        assert_eq!(SortingNetwork13::COMPARATORS, 48);
+        assert_eq!(BoseNelsonSortingNetwork32::COMPARATORS, 211);
+        assert_eq!(SortingNetwork32::COMPARATORS, 191);
+    }
+
+    #[test]
+    fn odd_even_transposition_sorting_networks() {
+        fn check<S>(network: S)
+        where
+            S: FixedSizeSortingNetwork + SortingNetworkTrait,
+        {
+            check_fixed_size_network(network);
+            assert_eq!(S::COMPARATORS, S::WIDTH * (S::WIDTH - 1) / 2);
+            assert!(S::PAIRS.iter().all(|&(min, max)| max == min + 1));
+        }
+        check_widths!(check, OddEvenTranspositionSortingNetwork: [
+            2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+            17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
+        ]);
+
+        assert_eq!(OddEvenTranspositionSortingNetwork3::DEPTH, 3);
+        assert_eq!(OddEvenTranspositionSortingNetwork16::DEPTH, 16);
+        assert_eq!(OddEvenTranspositionSortingNetwork32::DEPTH, 32);
+
+        let debug = format!("{:?}", OddEvenTranspositionSortingNetwork4::new());
+        assert_eq!(
+            debug,
+            "  0: ─┰───┰───\n  1: ─┸─┰─┸─┰─\n  2: ─┰─┸─┰─┸─\n  3: ─┸───┸───\n"
+        );
+    }
+
+    #[test]
+    fn optimal_sorting_networks() {
+        // Table entries must sort all inputs of zeros and ones,
+        // and improve upon odd-even merge sort, if anything:
+        fn check_size<S>(network: S)
+        where
+            S: FixedSizeSortingNetwork + SortingNetworkTrait,
+        {
+            check_fixed_size_network(network);
+            assert!(S::COMPARATORS <= sorting_network_pairs(S::WIDTH).unwrap().len());
+        }
+        fn check_depth<S>(network: S)
+        where
+            S: FixedSizeSortingNetwork + SortingNetworkTrait,
+        {
+            check_fixed_size_network(network);
+            assert!(S::DEPTH <= depth(S::WIDTH, sorting_network_pairs(S::WIDTH).unwrap()));
+        }
+        check_widths!(check_size, OptimalSortingNetwork: [2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+        check_widths!(check_depth, DepthOptimalSortingNetwork: [2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+
+        assert_eq!(SortingNetwork16::COMPARATORS, 63);
+        assert_eq!(SortingNetwork16::DEPTH, 10);
+    }
+
+    #[test]
+    fn optimal_sorting_network_tables() {
+        // Declared sizes and depths must match those of the table entries,
+        // which in turn must sort all inputs of zeros and ones:
+        fn check<S>(_: S, comparators: usize, stages: usize)
+        where
+            S: FixedSizeSortingNetwork,
+        {
+            assert_eq!((S::COMPARATORS, S::DEPTH), (comparators, stages), "width {}", S::WIDTH);
+            assert_eq!(S::PAIRS.len(), S::COMPARATORS);
+            assert_eq!(depth(S::WIDTH, S::PAIRS), S::DEPTH);
+            assert!(is_selecting(S::WIDTH, S::PAIRS, 0..S::WIDTH));
+        }
+        macro_rules! check_table {
+            ($family:ident: [$(($width:expr, $comparators:expr, $depth:expr)),*]) => {
+                $(check($family::<$width>::new(), $comparators, $depth);)*
+            };
+        }
+
+        // The published best-known sizes (proven optimal up to 12), tabulated up to 16 only:
+        check_table!(OptimalSortingNetwork: [
+            (2, 1, 1), (3, 3, 3), (4, 5, 3), (5, 9, 5), (6, 12, 5), (7, 16, 6), (8, 19, 6),
+            (9, 25, 7), (10, 29, 8), (11, 35, 8), (12, 39, 9), (13, 45, 10), (14, 51, 10),
+            (15, 56, 10), (16, 60, 10)
+        ]);
+        // The proven optimal depths, tabulated up to 16 only:
+        check_table!(DepthOptimalSortingNetwork: [
+            (2, 1, 1), (3, 3, 3), (4, 5, 3), (5, 9, 5), (6, 12, 5), (7, 16, 6), (8, 19, 6),
+            (9, 25, 7), (10, 31, 7), (11, 35, 8), (12, 40, 8), (13, 47, 9), (14, 52, 9),
+            (15, 57, 9), (16, 61, 9)
+        ]);
+    }
+
+    #[test]
+    fn sort_by_key() {
+        let mut items: Vec<_> = shuffled(12).into_iter().map(|i| (i, i * 2)).collect();
+        SortingNetwork12::new().sort_by_key(&mut items[..], |&(_, key)| key);
+        let expected: Vec<_> = (0..12).map(|i| (i, i * 2)).collect();
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn sort_by_cached_key() {
+        let mut calls = 0;
+        let mut items = shuffled(12);
+        SortingNetwork12::new().sort_by_cached_key(&mut items[..], |&item| {
+            calls += 1;
+            !item
+        });
+        let expected: Vec<_> = (0..12).rev().collect();
+        assert_eq!(items, expected);
+        assert_eq!(calls, 12);
+
+        let mut items = shuffled(12);
+        SortingNetworkTrait::sort_by_cached_key(&SortingNetwork12::new(), &mut items[..], |&item| !item);
+        assert_eq!(items, expected);
+
+        for length in 0..40 {
+            let mut items = shuffled(length);
+            RuntimeSortingNetwork::new().sort_by_cached_key(&mut items[..], |&item| item);
+            let expected: Vec<_> = (0..length).collect();
+            assert_eq!(items, expected);
+        }
+    }
+
+    #[test]
+    fn sort_with() {
+        use exchange::{Comparator, Counting, MinMax};
+
+        let mut items = shuffled(16);
+        let mut counting = Counting::new(MinMax::new());
+        SortingNetwork16::new().sort_with(&mut items[..], &mut counting);
+        assert_eq!(items, (0..16).collect::<Vec<_>>());
+        assert_eq!(counting.count(), SortingNetwork16::COMPARATORS);
+
+        for length in 0..40 {
+            let mut items = shuffled(length);
+            let comparator = Comparator::new(|lhs: &usize, rhs: &usize| rhs.cmp(lhs));
+            RuntimeSortingNetwork::new().sort_with(&mut items[..], comparator);
+            assert_eq!(items, (0..length).rev().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn sort_pairs() {
+        let mut keys = shuffled(16);
+        let mut values: Vec<_> = keys.iter().map(|key| key * 10).collect();
+        SortingNetwork16::new().sort_pairs(&mut keys[..], &mut values[..]);
+        assert_eq!(keys, (0..16).collect::<Vec<_>>());
+        assert_eq!(values, (0..16).map(|key| key * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sort_columns() {
+        let mut keys = shuffled(20);
+        let mut values: Vec<_> = keys.iter().map(|key| key * 10).collect();
+        let mut names: Vec<_> = keys.iter().map(|key| key.to_string()).collect();
+        let mut flags: Vec<_> = keys.iter().map(|key| key % 2 == 0).collect();
+        RuntimeSortingNetwork::new().sort_columns_by(
+            &mut keys[..],
+            (&mut values[..], &mut names[..], &mut flags[..]),
+            |lhs, rhs| rhs.cmp(lhs),
+        );
+        let expected: Vec<_> = (0..20).rev().collect();
+        assert_eq!(keys, expected);
+        assert_eq!(values, expected.iter().map(|key| key * 10).collect::<Vec<_>>());
+        assert_eq!(names, expected.iter().map(|key| key.to_string()).collect::<Vec<_>>());
+        assert_eq!(flags, expected.iter().map(|key| key % 2 == 0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn argsort() {
+        let items = [30, 10, 50, 20, 40];
+        let indices = SortingNetwork5::new().argsort(&items);
+        assert_eq!(indices, [1, 3, 0, 4, 2]);
+        assert_eq!(items, [30, 10, 50, 20, 40]);
+
+        let indices = SortingNetwork5::new().argsort_by(&items, |lhs, rhs| rhs.cmp(lhs));
+        assert_eq!(indices, [2, 4, 0, 3, 1]);
+
+        let items = shuffled(25);
+        let mut indices = [0; 25];
+        RuntimeSortingNetwork::new().argsort_into(&items[..], &mut indices[..]);
+        let sorted: Vec<_> = indices.iter().map(|&index| items[index]).collect();
+        assert_eq!(sorted, (0..25).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn sort_stable_by() {
+        for length in 0..40 {
+            let items: Vec<_> = shuffled(length).into_iter().map(|i| (i % 3, i)).collect();
+            let mut expected = items.clone();
+            expected.sort_by_key(|item| item.0);
+
+            let mut sorted = items.clone();
+            RuntimeSortingNetwork::new().sort_stable_by(&mut sorted[..], |lhs, rhs| lhs.0.cmp(&rhs.0));
+            assert_eq!(sorted, expected);
+        }
+
+        let items: Vec<_> = shuffled(16).into_iter().map(|i| (i % 4, i)).collect();
+        let mut expected = items.clone();
+        expected.sort_by_key(|item| item.0);
+
+        let mut sorted = items.clone();
+        SortingNetwork16::new().sort_stable_by(&mut sorted[..], |lhs, rhs| lhs.0.cmp(&rhs.0));
+        assert_eq!(sorted, expected);
+
+        let mut sorted = items.clone();
+        SortingNetworkTrait::sort_stable_by(&SortingNetwork16::new(), &mut sorted[..], |lhs, rhs| {
+            lhs.0.cmp(&rhs.0)
+        });
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn select() {
+        let network = SortingNetwork32::new();
+        for n in 0..32 {
+            let mut items = shuffled(32);
+            assert_eq!(*network.select_nth(&mut items[..], n), n);
+        }
+        for k in 0..33 {
+            let mut items = shuffled(32);
+            network.smallest_k(&mut items[..], k);
+            assert_eq!(items[..k], (0..k).collect::<Vec<_>>()[..]);
+
+            let mut items = shuffled(32);
+            network.largest_k(&mut items[..], k);
+            assert_eq!(items[(32 - k)..], ((32 - k)..32).collect::<Vec<_>>()[..]);
+        }
+
+        let mut items = shuffled(20);
+        RuntimeSortingNetwork::new().smallest_k_by(&mut items[..], 3, |lhs, rhs| rhs.cmp(lhs));
+        assert_eq!(items[..3], [19, 18, 17]);
+    }
+
+    #[test]
+    fn selection_network() {
+        let network = SortingNetwork64::new();
+        let full = SelectionNetwork::smallest(&network, 64, 64);
+        assert_eq!(full.comparators(), SortingNetwork64::COMPARATORS);
+        assert_eq!(full.saved_comparators(), 0);
+
+        let minimum = SelectionNetwork::smallest(&network, 64, 1);
+        assert_eq!(minimum.comparators(), 63);
+        assert_eq!(minimum.saved_comparators(), SortingNetwork64::COMPARATORS - 63);
+
+        let median = SelectionNetwork::nth(&network, 64, 32);
+        assert!(median.saved_comparators() > 0);
+        let mut items = shuffled(64);
+        median.sort(&mut items[..]);
+        assert_eq!(items[32], 32);
+    }
+
+    #[test]
+    fn sort_small() {
+        for length in 0..(MAX_WIDTH + 1) {
+            let mut items = shuffled(length);
+            assert_eq!(small::sort_small(&mut items[..]), Ok(()));
+            assert_eq!(items, (0..length).collect::<Vec<_>>());
+        }
+
+        let mut items = shuffled(MAX_WIDTH + 1);
+        let error = small::sort_small_by(&mut items[..], |lhs, rhs| lhs.cmp(rhs)).unwrap_err();
+        assert_eq!(error.length(), MAX_WIDTH + 1);
+        assert_eq!(error.max_length(), MAX_WIDTH);
+        assert_eq!(items, shuffled(MAX_WIDTH + 1));
+
+        let error: Box<dyn std::error::Error> = Box::new(error);
+        assert!(error.source().is_none());
+    }
+}
@@ -0,0 +1,207 @@
+//! Median-selection networks of minimal known size.
+//!
+//! The networks follow the ones collected in Devillard's
+//! "Fast median search: an ANSI C implementation", with the final
+//! comparators of Paeth's 9-element network brought into standard form.
+
+use std::cmp::Ordering;
+
+use super::swap_unchecked;
+
+/// Trait for median-selection networks of a fixed length
+pub trait FixedSizeMedianNetwork {
+    /// The length of arrays the network selects the median of
+    const WIDTH: usize;
+
+    /// The number of comparators of the network
+    const COMPARATORS: usize;
+
+    /// The number of parallel stages of the network
+    const DEPTH: usize;
+
+    /// The network's comparators as `(min, max)` pairs of wire indices
+    ///
+    /// After applying them the median is found at index `WIDTH / 2`.
+    /// Unlike `pairs()` this is usable from `const fn`s.
+    const PAIRS: &'static [(u8, u8)];
+
+    /// The network's comparators as `(min, max)` pairs of wire indices
+    ///
+    /// After applying them the median is found at index `WIDTH / 2`.
+    fn pairs() -> &'static [(u8, u8)] {
+        Self::PAIRS
+    }
+}
+
+/// Optimized median-selection network for arrays of length `N`.
+///
+/// Networks are available for `N` of `3`, `5`, `7`, `9` and `25`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Median<const N: usize>;
+
+impl<const N: usize> Median<N>
+where
+    Self: FixedSizeMedianNetwork,
+{
+    /// Creates a median-selection network for arrays of length `N`.
+    #[inline]
+    pub fn new() -> Self {
+        Median
+    }
+
+    /// Returns the median of the passed array
+    #[inline]
+    pub fn median<T>(&self, array: [T; N]) -> T
+    where
+        T: Ord,
+    {
+        self.median_by(array, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Returns the median of the passed array with respect to a comparator function
+    #[inline]
+    pub fn median_by<T, F>(&self, mut array: [T; N], compare: F) -> T
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        self.select_by(&mut array, compare);
+        IntoIterator::into_iter(array).nth(N / 2).unwrap()
+    }
+
+    /// Moves the median of the passed array to index `N / 2`, returning it
+    ///
+    /// The remaining elements are left in unspecified order.
+    #[inline]
+    pub fn select_by<'a, T, F>(&self, array: &'a mut [T; N], compare: F) -> &'a mut T
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        for &(min, max) in Self::pairs() {
+            unsafe {
+                swap_unchecked(&mut array[..], min as usize, max as usize, &compare);
+            }
+        }
+        &mut array[N / 2]
+    }
+}
+
+macro_rules! impl_median_network {
+    ($width:expr, depth: $depth:expr, [$(($min:expr, $max:expr)),* $(,)*]) => {
+        impl FixedSizeMedianNetwork for Median<$width> {
+            const WIDTH: usize = $width;
+            const COMPARATORS: usize = [$(($min, $max)),*].len();
+            const DEPTH: usize = $depth;
+            const PAIRS: &'static [(u8, u8)] = &[$(($min, $max)),*];
+        }
+    }
+}
+
+impl_median_network!(3, depth: 3, [(0, 1), (1, 2), (0, 1)]);
+
+impl_median_network!(5, depth: 5, [
+    (0, 1), (3, 4), (0, 3), (1, 4), (1, 2), (2, 3), (1, 2),
+]);
+
+impl_median_network!(7, depth: 7, [
+    (0, 5), (0, 3), (1, 6), (2, 4), (0, 1), (3, 5), (2, 6),
+    (2, 3), (3, 6), (4, 5), (1, 4), (1, 3), (3, 4),
+]);
+
+impl_median_network!(9, depth: 9, [
+    (1, 2), (4, 5), (7, 8), (0, 1), (3, 4), (6, 7), (1, 2),
+    (4, 5), (7, 8), (0, 3), (5, 8), (4, 7), (3, 6), (1, 4),
+    (2, 5), (4, 7), (2, 4), (2, 6), (4, 6),
+]);
+
+impl_median_network!(25, depth: 24, [
+    (0, 1), (3, 4), (2, 4), (2, 3), (6, 7), (5, 7), (5, 6),
+    (9, 10), (8, 10), (8, 9), (12, 13), (11, 13), (11, 12), (15, 16),
+    (14, 16), (14, 15), (18, 19), (17, 19), (17, 18), (21, 22), (20, 22),
+    (20, 21), (23, 24), (2, 5), (3, 6), (0, 6), (0, 3), (4, 7),
+    (1, 7), (1, 4), (11, 14), (8, 14), (8, 11), (12, 15), (9, 15),
+    (9, 12), (13, 16), (10, 16), (10, 13), (20, 23), (17, 23), (17, 20),
+    (21, 24), (18, 24), (18, 21), (19, 22), (8, 17), (9, 18), (0, 18),
+    (0, 9), (10, 19), (1, 19), (1, 10), (11, 20), (2, 20), (2, 11),
+    (12, 21), (3, 21), (3, 12), (13, 22), (4, 22), (4, 13), (14, 23),
+    (5, 23), (5, 14), (15, 24), (6, 24), (6, 15), (7, 16), (7, 19),
+    (13, 21), (15, 23), (7, 13), (7, 15), (1, 9), (3, 11), (5, 17),
+    (11, 17), (9, 17), (4, 10), (6, 12), (7, 14), (4, 6), (4, 7),
+    (12, 14), (10, 14), (6, 7), (10, 12), (6, 10), (6, 17), (12, 17),
+    (7, 17), (7, 10), (12, 18), (7, 12), (10, 18), (12, 20), (10, 20),
+    (10, 12),
+]);
+
+/// Returns the median of the passed array with respect to a comparator function
+#[inline]
+pub fn median_by<T, F, const N: usize>(array: [T; N], compare: F) -> T
+where
+    F: Fn(&T, &T) -> Ordering,
+    Median<N>: FixedSizeMedianNetwork,
+{
+    Median::<N>::new().median_by(array, compare)
+}
+
+/// Returns the median of 3 elements, using 3 comparators.
+#[inline]
+pub fn median3<T: Ord>(array: [T; 3]) -> T {
+    Median::<3>::new().median(array)
+}
+
+/// Returns the median of 5 elements, using 7 comparators.
+#[inline]
+pub fn median5<T: Ord>(array: [T; 5]) -> T {
+    Median::<5>::new().median(array)
+}
+
+/// Returns the median of 7 elements, using 13 comparators.
+#[inline]
+pub fn median7<T: Ord>(array: [T; 7]) -> T {
+    Median::<7>::new().median(array)
+}
+
+/// Returns the median of 9 elements, using 19 comparators.
+#[inline]
+pub fn median9<T: Ord>(array: [T; 9]) -> T {
+    Median::<9>::new().median(array)
+}
+
+/// Returns the median of 25 elements, using 99 comparators.
+#[inline]
+pub fn median25<T: Ord>(array: [T; 25]) -> T {
+    Median::<25>::new().median(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tests::{is_selecting, shuffled};
+
+    #[test]
+    fn median() {
+        assert_eq!(median3([3, 1, 2]), 2);
+        assert_eq!(median5([5, 1, 4, 2, 3]), 3);
+        assert_eq!(median7([7, 1, 6, 2, 5, 3, 4]), 4);
+        assert_eq!(median9([9, 1, 8, 2, 7, 3, 6, 4, 5]), 5);
+        assert_eq!(median_by([54, 10, 43, 21, 32], |lhs, rhs| (lhs % 10).cmp(&(rhs % 10))), 32);
+
+        let mut items = [0; 25];
+        items.copy_from_slice(&shuffled(25)[..]);
+        assert_eq!(median25(items), 12);
+        assert_eq!(*Median::<25>::new().select_by(&mut items, |lhs, rhs| lhs.cmp(rhs)), 12);
+    }
+
+    #[test]
+    fn median_networks() {
+        fn check<M: FixedSizeMedianNetwork>() {
+            let pairs = M::PAIRS;
+            assert_eq!(pairs.len(), M::COMPARATORS);
+            assert!(pairs.iter().all(|&(min, max)| min < max));
+            assert!(is_selecting(M::WIDTH, pairs, (M::WIDTH / 2)..(M::WIDTH / 2 + 1)));
+        }
+        check::<Median<3>>();
+        check::<Median<5>>();
+        check::<Median<7>>();
+        check::<Median<9>>();
+        check::<Median<25>>();
+    }
+}
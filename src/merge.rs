@@ -0,0 +1,214 @@
+//! Merging networks for two sorted runs.
+
+use std::cmp::Ordering;
+
+use exchange::{CompareExchange, Comparator};
+use generate::{BitonicMerge, OddEvenMerge};
+
+/// Trait for merging networks
+pub trait MergingNetworkTrait {
+    /// Merges the sorted runs `slice[..mid]` and `slice[mid..]`
+    fn merge<T>(&self, slice: &mut [T], mid: usize)
+    where
+        T: Ord,
+    {
+        self.merge_by(slice, mid, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Merges the runs `slice[..mid]` and `slice[mid..]`,
+    /// both sorted with respect to a comparator function
+    fn merge_by<T, F>(&self, slice: &mut [T], mid: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let len = slice.len();
+        assert!(mid <= len, "Expected mid of at most {}", len);
+        let mut exchange = Comparator::new(&compare);
+        self.for_each_pair(mid, len - mid, |min, max| {
+            exchange.compare_exchange(slice, min, max)
+        });
+    }
+
+    /// Merges the sorted slices `lhs` and `rhs` into `output`
+    fn merge_into<T>(&self, lhs: &[T], rhs: &[T], output: &mut [T])
+    where
+        T: Ord + Clone,
+    {
+        self.merge_into_by(lhs, rhs, output, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    /// Merges the slices `lhs` and `rhs`, both sorted with respect
+    /// to a comparator function, into `output`
+    fn merge_into_by<T, F>(&self, lhs: &[T], rhs: &[T], output: &mut [T], compare: F)
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let len = lhs.len() + rhs.len();
+        assert!(output.len() == len, "Expected output of length {}", len);
+        let (head, tail) = output.split_at_mut(lhs.len());
+        head.clone_from_slice(lhs);
+        tail.clone_from_slice(rhs);
+        self.merge_by(output, lhs.len(), compare)
+    }
+
+    /// Calls `visit` with the `(min, max)` wire indices of each of the
+    /// network's comparators for runs of lengths `lhs` and `rhs`, in order
+    fn for_each_pair<F>(&self, lhs: usize, rhs: usize, visit: F)
+    where
+        F: FnMut(usize, usize);
+}
+
+/// Batcher's odd-even merging network for runs of arbitrary lengths.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OddEvenMergingNetwork;
+
+impl OddEvenMergingNetwork {
+    /// Creates an odd-even merging network for runs of arbitrary lengths.
+    pub fn new() -> Self {
+        OddEvenMergingNetwork
+    }
+}
+
+impl MergingNetworkTrait for OddEvenMergingNetwork {
+    fn for_each_pair<F>(&self, lhs: usize, rhs: usize, mut visit: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        for pair in OddEvenMerge::new(lhs, rhs) {
+            visit(pair.min, pair.max);
+        }
+    }
+}
+
+/// Bitonic merging network for runs of arbitrary lengths.
+///
+/// Only available for runtime lengths: fixed-size merging networks
+/// are generated from the odd-even merge alone, see `MergingNetwork`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BitonicMergingNetwork;
+
+impl BitonicMergingNetwork {
+    /// Creates a bitonic merging network for runs of arbitrary lengths.
+    pub fn new() -> Self {
+        BitonicMergingNetwork
+    }
+}
+
+impl MergingNetworkTrait for BitonicMergingNetwork {
+    fn for_each_pair<F>(&self, lhs: usize, rhs: usize, mut visit: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        for pair in BitonicMerge::new(lhs, rhs) {
+            visit(pair.min, pair.max);
+        }
+    }
+}
+
+/// Trait for merging networks of fixed run lengths
+pub trait FixedSizeMergingNetwork {
+    /// The length of the first run
+    const LHS: usize;
+
+    /// The length of the second run
+    const RHS: usize;
+
+    /// The number of comparators of the network
+    const COMPARATORS: usize;
+
+    /// The number of parallel stages of the network
+    const DEPTH: usize;
+
+    /// The network's comparators as `(min, max)` pairs of wire indices
+    ///
+    /// Unlike `pairs()` this is usable from `const fn`s.
+    const PAIRS: &'static [(u8, u8)];
+
+    /// The network's comparators as `(min, max)` pairs of wire indices
+    fn pairs() -> &'static [(u8, u8)] {
+        Self::PAIRS
+    }
+}
+
+/// Optimized odd-even merging network for runs of lengths `M` and `N`.
+///
+/// Networks are generated for every `M` and `N` from `1` up to `8`
+/// (or up to `16` with the `wide-networks` feature)
+/// and are also available through aliases, such as `MergingNetwork4x8`.
+///
+/// There is no fixed-size counterpart of `BitonicMergingNetwork`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MergingNetwork<const M: usize, const N: usize>;
+
+impl<const M: usize, const N: usize> MergingNetwork<M, N> {
+    /// Creates a merging network for runs of lengths `M` and `N`.
+    #[inline]
+    pub fn new() -> Self {
+        MergingNetwork
+    }
+}
+
+impl<const M: usize, const N: usize> MergingNetworkTrait for MergingNetwork<M, N>
+where
+    Self: FixedSizeMergingNetwork,
+{
+    fn for_each_pair<F>(&self, lhs: usize, rhs: usize, mut visit: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        assert!(lhs == M && rhs == N, "Expected runs of lengths {} and {}", M, N);
+        for &(min, max) in Self::pairs() {
+            visit(min as usize, max as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+    use super::*;
+    use {MergingNetwork5x7, MergingNetwork8x8};
+
+    #[test]
+    fn merging_networks() {
+        fn check<M: MergingNetworkTrait>(network: M) {
+            for lhs in 0..20 {
+                for rhs in 0..20 {
+                    // By the zero-one principle it suffices to merge all sorted runs of zeros and ones:
+                    for lhs_zeros in 0..(lhs + 1) {
+                        for rhs_zeros in 0..(rhs + 1) {
+                            let mut items: Vec<_> = (0..lhs).map(|i| (i >= lhs_zeros) as u8).collect();
+                            items.extend((0..rhs).map(|i| (i >= rhs_zeros) as u8));
+                            network.merge(&mut items[..], lhs);
+                            assert!(items.windows(2).all(|pair| pair[0] <= pair[1]));
+                        }
+                    }
+                }
+            }
+        }
+        check(OddEvenMergingNetwork::new());
+        check(BitonicMergingNetwork::new());
+    }
+
+    #[test]
+    fn merge_into() {
+        let lhs = [1, 4, 6, 9, 11];
+        let rhs = [0, 2, 3, 5, 7, 8, 10];
+        let mut output = [0; 12];
+        MergingNetwork5x7::new().merge_into(&lhs, &rhs, &mut output);
+        assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+        let mut output = [0; 12];
+        BitonicMergingNetwork::new().merge_into_by(&lhs, &rhs, &mut output, |lhs, rhs| lhs.cmp(rhs));
+        assert_eq!(output, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+        let mut items = [3, 1, 0, 2];
+        MergingNetwork::<1, 3>::new().merge(&mut items, 1);
+        assert_eq!(items, [0, 1, 2, 3]);
+
+        assert_eq!(MergingNetwork8x8::COMPARATORS, 25);
+        assert_eq!(MergingNetwork8x8::DEPTH, 4);
+        assert_eq!(MergingNetwork8x8::PAIRS.len(), 25);
+    }
+}
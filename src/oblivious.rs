@@ -0,0 +1,157 @@
+//! Data-oblivious sorting of primitive integers.
+//!
+//! A sorting network's comparators only depend on the length of its input,
+//! never on its values. Pairing a network with the `Oblivious` compare-exchange
+//! primitive (e.g. via `SortingNetworkTrait::sort_oblivious`) therefore results
+//! in a sort whose control flow and memory access pattern are independent
+//! of the data being sorted, as required for handling secrets in cryptographic code.
+//!
+//! # Guarantees
+//!
+//! - The sequence of comparators, and hence of memory accesses,
+//!   is determined by the network and the slice's length alone.
+//!   This holds for `SortingNetwork<N>`, `RuntimeSortingNetwork` and `SelectionNetwork`,
+//!   but not for data-dependent algorithms such as `network_sort`.
+//! - Each compare-exchange is computed with bitwise and wrapping arithmetic operations only:
+//!   there are no data-dependent branches, table lookups or early exits in the source.
+//!   The swap mask is passed through `hint::black_box` to keep the optimizer from
+//!   recognizing the select and turning it back into a branch.
+//! - Signed integers are compared by flipping their sign bit and comparing them as unsigned.
+//!
+//! # Caveats
+//!
+//! Rust and LLVM make no formal constant-time promises, so these properties are upheld
+//! on a best-effort basis. Code with strict requirements should inspect the generated
+//! assembly for its targets. Also, instructions such as 128-bit subtraction
+//! may not execute in constant time on every platform.
+
+use std::hint::black_box;
+
+use exchange::CompareExchange;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Trait for primitive integers that can be compare-exchanged in constant time
+///
+/// Implemented for `u8`…`u128`, `usize`, `i8`…`i128` and `isize`.
+pub trait ObliviousInteger: Copy + sealed::Sealed {
+    /// Orders `min` and `max`, such that `*min <= *max` afterwards,
+    /// without branching on their values.
+    fn oblivious_exchange(min: &mut Self, max: &mut Self);
+}
+
+macro_rules! impl_oblivious_integer {
+    ($($type:ident as $unsigned:ident),*) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl ObliviousInteger for $type {
+                #[inline]
+                fn oblivious_exchange(min: &mut Self, max: &mut Self) {
+                    let bits = <$unsigned>::BITS;
+                    // Flipping the sign bit maps signed integers onto unsigned ones, preserving order:
+                    let bias: $unsigned = if <$type>::MIN == 0 { 0 } else { 1 << (bits - 1) };
+                    let (lhs, rhs) = ((*min as $unsigned) ^ bias, (*max as $unsigned) ^ bias);
+                    // `rhs < lhs` as `0` or `1`, without comparisons (Hacker's Delight, 2-12):
+                    let greater = ((!rhs & lhs) | (!(rhs ^ lhs) & rhs.wrapping_sub(lhs))) >> (bits - 1);
+                    let mask = black_box(greater).wrapping_neg();
+                    let swap = (lhs ^ rhs) & mask;
+                    *min = ((*min as $unsigned) ^ swap) as $type;
+                    *max = ((*max as $unsigned) ^ swap) as $type;
+                }
+            }
+        )*
+    }
+}
+
+impl_oblivious_integer!(
+    u8 as u8,
+    u16 as u16,
+    u32 as u32,
+    u64 as u64,
+    u128 as u128,
+    usize as usize,
+    i8 as u8,
+    i16 as u16,
+    i32 as u32,
+    i64 as u64,
+    i128 as u128,
+    isize as usize
+);
+
+/// Constant-time compare-exchange primitive for primitive integers
+///
+/// See the module documentation for what is (and isn't) guaranteed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Oblivious;
+
+impl Oblivious {
+    /// Creates a constant-time primitive.
+    #[inline]
+    pub fn new() -> Self {
+        Oblivious
+    }
+}
+
+impl<T> CompareExchange<T> for Oblivious
+where
+    T: ObliviousInteger,
+{
+    #[inline]
+    fn compare_exchange(&mut self, slice: &mut [T], min: usize, max: usize) {
+        let (mut lhs, mut rhs) = (slice[min], slice[max]);
+        T::oblivious_exchange(&mut lhs, &mut rhs);
+        slice[min] = lhs;
+        slice[max] = rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+    use super::*;
+
+    fn exchanged<T: ObliviousInteger>(mut lhs: T, mut rhs: T) -> (T, T) {
+        T::oblivious_exchange(&mut lhs, &mut rhs);
+        (lhs, rhs)
+    }
+
+    #[test]
+    fn oblivious_exchange() {
+        for lhs in 0..=255u8 {
+            for rhs in 0..=255u8 {
+                assert_eq!(exchanged(lhs, rhs), (lhs.min(rhs), lhs.max(rhs)));
+                let (lhs, rhs) = (lhs as i8, rhs as i8);
+                assert_eq!(exchanged(lhs, rhs), (lhs.min(rhs), lhs.max(rhs)));
+            }
+        }
+
+        let extremes = [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX - 1, i128::MAX];
+        for &lhs in &extremes {
+            for &rhs in &extremes {
+                assert_eq!(exchanged(lhs, rhs), (lhs.min(rhs), lhs.max(rhs)));
+                let (lhs, rhs) = (lhs as u128, rhs as u128);
+                assert_eq!(exchanged(lhs, rhs), (lhs.min(rhs), lhs.max(rhs)));
+                let (lhs, rhs) = (lhs as i64, rhs as i64);
+                assert_eq!(exchanged(lhs, rhs), (lhs.min(rhs), lhs.max(rhs)));
+            }
+        }
+    }
+
+    #[test]
+    fn sort_oblivious() {
+        use {RuntimeSortingNetwork, SortingNetwork16, SortingNetworkTrait};
+
+        let mut items: Vec<i32> = (0..16).map(|index| (index * 7919 % 16) - 8).collect();
+        SortingNetwork16::new().sort_oblivious(&mut items[..]);
+        assert_eq!(items, (-8..8).collect::<Vec<_>>());
+
+        for length in 0..40u64 {
+            let mut items: Vec<u64> = (0..length).map(|index| (index * 313373) % length).collect();
+            RuntimeSortingNetwork::new().sort_oblivious(&mut items[..]);
+            assert_eq!(items, (0..length).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Selection networks, obtained by pruning sorting networks.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+use std::prelude::v1::*;
+
+use super::{swap_unchecked, SortingNetworkTrait};
+
+/// Selection network for slices of a specific length
+///
+/// Obtained from a sorting network by dropping every comparator
+/// that cannot affect the requested output positions. After running it,
+/// each of these positions holds the element a full sort would have put there.
+/// The remaining positions hold the other elements, in unspecified order.
+///
+/// Pruning walks all comparators of the full network and allocates,
+/// which may well cost more than the comparators it saves.
+/// So when selecting from many slices of the same length, build a selection network
+/// once and reuse it, rather than calling `SortingNetworkTrait::select_nth` & co.,
+/// which prune on every call.
+#[derive(Clone, Debug)]
+pub struct SelectionNetwork {
+    len: usize,
+    outputs: Range<usize>,
+    pairs: Vec<(usize, usize)>,
+    total: usize,
+}
+
+impl SelectionNetwork {
+    /// Creates a selection network for slices of length `len` by pruning `network`,
+    /// keeping only the comparators affecting the positions in `outputs`.
+    pub fn new<N>(network: &N, len: usize, outputs: Range<usize>) -> Self
+    where
+        N: SortingNetworkTrait + ?Sized,
+    {
+        assert!(outputs.end <= len, "Expected outputs within 0..{}", len);
+
+        let mut pairs = vec![];
+        network.for_each_pair(len, |min, max| {
+            assert!(min < max && max < len, "Invalid comparator ({}, {})", min, max);
+            pairs.push((min, max));
+        });
+        let total = pairs.len();
+
+        let mut is_relevant = vec![false; len];
+        for index in outputs.clone() {
+            is_relevant[index] = true;
+        }
+        let mut pairs: Vec<_> = pairs
+            .into_iter()
+            .rev()
+            .filter(|&(min, max)| {
+                let is_kept = is_relevant[min] || is_relevant[max];
+                if is_kept {
+                    is_relevant[min] = true;
+                    is_relevant[max] = true;
+                }
+                is_kept
+            })
+            .collect();
+        pairs.reverse();
+
+        Self {
+            len,
+            outputs,
+            pairs,
+            total,
+        }
+    }
+
+    /// Creates a selection network placing the `n`-th smallest element at index `n`.
+    pub fn nth<N>(network: &N, len: usize, n: usize) -> Self
+    where
+        N: SortingNetworkTrait + ?Sized,
+    {
+        Self::new(network, len, n..(n + 1))
+    }
+
+    /// Creates a selection network placing the `k` smallest elements,
+    /// in order, at the front of the slice.
+    pub fn smallest<N>(network: &N, len: usize, k: usize) -> Self
+    where
+        N: SortingNetworkTrait + ?Sized,
+    {
+        Self::new(network, len, 0..k)
+    }
+
+    /// Creates a selection network placing the `k` largest elements,
+    /// in order, at the back of the slice.
+    pub fn largest<N>(network: &N, len: usize, k: usize) -> Self
+    where
+        N: SortingNetworkTrait + ?Sized,
+    {
+        assert!(k <= len, "Expected at most {} elements", len);
+        Self::new(network, len, (len - k)..len)
+    }
+
+    /// The length of slices the network selects from
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the network selects from empty slices.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The positions the network selects elements for
+    pub fn outputs(&self) -> Range<usize> {
+        self.outputs.clone()
+    }
+
+    /// The number of comparators of the pruned network
+    pub fn comparators(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// The number of comparators saved compared with the full sorting network
+    pub fn saved_comparators(&self) -> usize {
+        self.total - self.pairs.len()
+    }
+}
+
+impl SortingNetworkTrait for SelectionNetwork {
+    fn sort_by<T, F>(&self, slice: &mut [T], compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let len = slice.len();
+        assert!(len == self.len, "Expected slice of length {}", self.len);
+        for &(min, max) in &self.pairs {
+            unsafe {
+                swap_unchecked(slice, min, max, &compare);
+            }
+        }
+    }
+
+    fn for_each_pair<F>(&self, len: usize, mut visit: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        assert!(len == self.len, "Expected slice of length {}", self.len);
+        for &(min, max) in &self.pairs {
+            visit(min, max);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Sorting of short slices, whose length is only known at runtime.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::{sorting_network_pairs, swap_unchecked, MAX_WIDTH};
+
+/// Error returned when a slice is longer than the largest available network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LengthError {
+    len: usize,
+}
+
+impl LengthError {
+    /// The length of the rejected slice
+    pub fn length(&self) -> usize {
+        self.len
+    }
+
+    /// The length of the largest available network
+    pub fn max_length(&self) -> usize {
+        MAX_WIDTH
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "slice of length {} exceeds largest sorting network of length {}",
+            self.len, MAX_WIDTH
+        )
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl ::std::error::Error for LengthError {}
+
+/// Sorts the passed slice with the generated network matching its length
+///
+/// Every length from `2` up to `MAX_WIDTH` has a dedicated network,
+/// which is picked through a jump table. Shorter slices are sorted already.
+pub fn sort_small<T>(slice: &mut [T]) -> Result<(), LengthError>
+where
+    T: Ord,
+{
+    sort_small_by(slice, |lhs, rhs| lhs.cmp(rhs))
+}
+
+/// Sorts the passed slice with a comparator function
+/// and the generated network matching its length
+///
+/// Every length from `2` up to `MAX_WIDTH` has a dedicated network,
+/// which is picked through a jump table. Shorter slices are sorted already.
+pub fn sort_small_by<T, F>(slice: &mut [T], compare: F) -> Result<(), LengthError>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    if len <= 1 {
+        return Ok(());
+    }
+    let pairs = sorting_network_pairs(len).ok_or(LengthError { len })?;
+    for &(min, max) in pairs {
+        unsafe {
+            swap_unchecked(slice, min as usize, max as usize, &compare);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,329 @@
+//! Logical sequences of elements that networks can sort in place,
+//! without them having to be contiguous in memory.
+
+use std::cmp::Ordering;
+use std::ptr;
+
+#[cfg(any(feature = "std", test))]
+use std::collections::VecDeque;
+
+/// Trait for indexable sequences of elements ("wires") whose elements can be exchanged
+///
+/// Implemented for slices, strided views (`Strided`), gathered views (`Gathered`),
+/// `VecDeque`, as well as mutable references to wires.
+pub trait Wires {
+    /// The type of element carried by each wire.
+    type Item;
+
+    /// Returns the number of wires.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no wires.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the element on wire `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    fn wire(&self, index: usize) -> &Self::Item;
+
+    /// Exchanges the elements on wires `lhs` and `rhs`.
+    ///
+    /// Panics if either index is out of bounds.
+    fn swap_wires(&mut self, lhs: usize, rhs: usize);
+
+    /// Returns a reference to the element on wire `index`, without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index < self.len()`.
+    /// Implementations overriding this method must in turn make sure
+    /// that every such `index` refers to a valid element.
+    #[inline]
+    unsafe fn wire_unchecked(&self, index: usize) -> &Self::Item {
+        self.wire(index)
+    }
+
+    /// Moves the lesser (as determined by `compare`) of the elements
+    /// on wires `lhs` and `rhs` to `lhs`, and the greater one to `rhs`,
+    /// without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `lhs < self.len()` and `rhs < self.len()`.
+    /// Implementations overriding this method must in turn make sure
+    /// that every such index refers to a valid element.
+    #[inline]
+    unsafe fn exchange_unchecked<F>(&mut self, lhs: usize, rhs: usize, compare: F)
+    where
+        F: Fn(&Self::Item, &Self::Item) -> Ordering,
+    {
+        if compare(self.wire_unchecked(lhs), self.wire_unchecked(rhs)) != Ordering::Less {
+            self.swap_wires(lhs, rhs);
+        }
+    }
+}
+
+impl<T> Wires for [T] {
+    type Item = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn wire(&self, index: usize) -> &T {
+        &self[index]
+    }
+
+    #[inline]
+    fn swap_wires(&mut self, lhs: usize, rhs: usize) {
+        self.swap(lhs, rhs)
+    }
+
+    #[inline]
+    unsafe fn wire_unchecked(&self, index: usize) -> &T {
+        self.get_unchecked(index)
+    }
+
+    #[inline]
+    unsafe fn exchange_unchecked<F>(&mut self, lhs: usize, rhs: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let ptr = self.as_mut_ptr();
+        exchange_ptrs(ptr.add(lhs), ptr.add(rhs), compare)
+    }
+}
+
+impl<W> Wires for &mut W
+where
+    W: Wires + ?Sized,
+{
+    type Item = W::Item;
+
+    #[inline]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    #[inline]
+    fn wire(&self, index: usize) -> &W::Item {
+        (**self).wire(index)
+    }
+
+    #[inline]
+    fn swap_wires(&mut self, lhs: usize, rhs: usize) {
+        (**self).swap_wires(lhs, rhs)
+    }
+
+    #[inline]
+    unsafe fn wire_unchecked(&self, index: usize) -> &W::Item {
+        (**self).wire_unchecked(index)
+    }
+
+    #[inline]
+    unsafe fn exchange_unchecked<F>(&mut self, lhs: usize, rhs: usize, compare: F)
+    where
+        F: Fn(&W::Item, &W::Item) -> Ordering,
+    {
+        (**self).exchange_unchecked(lhs, rhs, compare)
+    }
+}
+
+#[cfg(any(feature = "std", test))]
+impl<T> Wires for VecDeque<T> {
+    type Item = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    #[inline]
+    fn wire(&self, index: usize) -> &T {
+        &self[index]
+    }
+
+    #[inline]
+    fn swap_wires(&mut self, lhs: usize, rhs: usize) {
+        self.swap(lhs, rhs)
+    }
+
+    #[inline]
+    unsafe fn wire_unchecked(&self, index: usize) -> &T {
+        let (head, tail) = self.as_slices();
+        if index < head.len() {
+            head.get_unchecked(index)
+        } else {
+            tail.get_unchecked(index - head.len())
+        }
+    }
+}
+
+/// The elements at `offset`, `offset + stride`, `offset + 2 * stride`, … of a slice
+///
+/// For a row-major matrix with `columns` columns,
+/// `Strided::new(matrix, column, columns)` is the column at index `column`.
+#[derive(Debug)]
+pub struct Strided<'a, T: 'a> {
+    slice: &'a mut [T],
+    offset: usize,
+    stride: usize,
+    len: usize,
+}
+
+impl<'a, T> Strided<'a, T> {
+    /// Creates a view of every `stride`-th element of `slice`, starting at `offset`.
+    ///
+    /// Panics if `stride` is zero.
+    pub fn new(slice: &'a mut [T], offset: usize, stride: usize) -> Self {
+        assert!(stride > 0, "Expected non-zero stride");
+        let len = if offset < slice.len() {
+            (slice.len() - offset - 1) / stride + 1
+        } else {
+            0
+        };
+        Strided {
+            slice,
+            offset,
+            stride,
+            len,
+        }
+    }
+
+    #[inline]
+    fn index(&self, index: usize) -> usize {
+        self.offset + index * self.stride
+    }
+}
+
+impl<'a, T> Wires for Strided<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn wire(&self, index: usize) -> &T {
+        assert!(index < self.len, "Index {} out of bounds", index);
+        &self.slice[self.index(index)]
+    }
+
+    #[inline]
+    fn swap_wires(&mut self, lhs: usize, rhs: usize) {
+        assert!(lhs < self.len && rhs < self.len, "Indices out of bounds");
+        let (lhs, rhs) = (self.index(lhs), self.index(rhs));
+        self.slice.swap(lhs, rhs)
+    }
+
+    #[inline]
+    unsafe fn wire_unchecked(&self, index: usize) -> &T {
+        self.slice.get_unchecked(self.index(index))
+    }
+
+    #[inline]
+    unsafe fn exchange_unchecked<F>(&mut self, lhs: usize, rhs: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let (lhs, rhs) = (self.index(lhs), self.index(rhs));
+        self.slice.exchange_unchecked(lhs, rhs, compare)
+    }
+}
+
+/// The elements of a slice at the given `indices`, in that order
+///
+/// Indices are not required to be distinct, but only distinct indices get sorted:
+/// a repeated index puts the same element on several wires, so that moving it
+/// on one of them changes the others, too. Networks then leave the wires in an
+/// unspecified order, though the slice keeps holding each of its elements exactly once.
+#[derive(Debug)]
+pub struct Gathered<'a, T: 'a> {
+    slice: &'a mut [T],
+    indices: &'a [usize],
+}
+
+impl<'a, T> Gathered<'a, T> {
+    /// Creates a view of the elements of `slice` at `indices`.
+    ///
+    /// Panics if any of `indices` is out of bounds.
+    /// Repeated indices are not checked for, and leave the wires unsorted (see `Gathered`).
+    pub fn new(slice: &'a mut [T], indices: &'a [usize]) -> Self {
+        let len = slice.len();
+        assert!(
+            indices.iter().all(|&index| index < len),
+            "Expected indices less than {}",
+            len
+        );
+        Gathered { slice, indices }
+    }
+}
+
+impl<'a, T> Wires for Gathered<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    #[inline]
+    fn wire(&self, index: usize) -> &T {
+        &self.slice[self.indices[index]]
+    }
+
+    #[inline]
+    fn swap_wires(&mut self, lhs: usize, rhs: usize) {
+        self.slice.swap(self.indices[lhs], self.indices[rhs])
+    }
+
+    #[inline]
+    unsafe fn wire_unchecked(&self, index: usize) -> &T {
+        // All indices were checked against the slice's length on construction:
+        self.slice.get_unchecked(*self.indices.get_unchecked(index))
+    }
+
+    #[inline]
+    unsafe fn exchange_unchecked<F>(&mut self, lhs: usize, rhs: usize, compare: F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let lhs = *self.indices.get_unchecked(lhs);
+        let rhs = *self.indices.get_unchecked(rhs);
+        self.slice.exchange_unchecked(lhs, rhs, compare)
+    }
+}
+
+/// Branchlessly moves the lesser of `*lhs` and `*rhs` to `lhs`, the greater to `rhs`.
+///
+/// Both pointers must be valid for reads and writes. They may be equal.
+///
+/// This is panic-safe: `compare` is the only code that may unwind, and it runs
+/// before anything gets moved. The reads and writes that follow can't unwind,
+/// so each of the two elements is moved out and written back exactly once.
+/// Likewise a `compare` that isn't a total order merely causes a different
+/// (but still valid) exchange, leaving the slice a permutation of its input.
+#[inline]
+unsafe fn exchange_ptrs<T, F>(lhs_ptr: *mut T, rhs_ptr: *mut T, compare: F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    // The shared references passed to `compare` don't outlive this statement,
+    // so the writes below don't invalidate any references still in use:
+    let is_ordered = compare(&*lhs_ptr, &*rhs_ptr) == Ordering::Less;
+
+    let min_ptr = if is_ordered { lhs_ptr } else { rhs_ptr };
+    let max_ptr = if is_ordered { rhs_ptr } else { lhs_ptr };
+
+    let min_val = ptr::read(min_ptr);
+    let max_val = ptr::read(max_ptr);
+
+    ptr::write(lhs_ptr, min_val);
+    ptr::write(rhs_ptr, max_val);
+}